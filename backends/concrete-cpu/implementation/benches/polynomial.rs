@@ -0,0 +1,346 @@
+use concrete_cpu::internals::*;
+use concrete_fft::ordered::FftAlgo;
+use concrete_fft::unordered::{Method, Plan};
+use criterion::{criterion_group, criterion_main, Criterion};
+use dyn_stack::{DynStack, GlobalMemBuffer, ReborrowMut, StackReq};
+
+fn random_polynomial(polynomial_size: usize, seed: u64) -> Vec<u64> {
+    (0..polynomial_size as u64)
+        .map(|i| (i ^ seed).wrapping_mul(0x9e37_79b9_7f4a_7c15))
+        .collect()
+}
+
+pub fn add_sub_benchmark(c: &mut Criterion) {
+    for polynomial_size in [1024, 4096] {
+        let rhs = random_polynomial(polynomial_size, 1);
+
+        c.bench_function(&format!("polynomial-add-u64-{polynomial_size}"), |b| {
+            let mut lhs = random_polynomial(polynomial_size, 0);
+            b.iter(|| {
+                Polynomial::new(lhs.as_mut_slice(), polynomial_size)
+                    .update_with_wrapping_add(Polynomial::new(&rhs, polynomial_size));
+            });
+        });
+
+        c.bench_function(
+            &format!("polynomial-add-u64-scalar-{polynomial_size}"),
+            |b| {
+                let mut lhs = random_polynomial(polynomial_size, 0);
+                b.iter(|| wrapping_add_scalar(&mut lhs, &rhs));
+            },
+        );
+
+        c.bench_function(&format!("polynomial-sub-u64-{polynomial_size}"), |b| {
+            let mut lhs = random_polynomial(polynomial_size, 0);
+            b.iter(|| {
+                Polynomial::new(lhs.as_mut_slice(), polynomial_size)
+                    .update_with_wrapping_sub(Polynomial::new(&rhs, polynomial_size));
+            });
+        });
+
+        c.bench_function(
+            &format!("polynomial-sub-u64-scalar-{polynomial_size}"),
+            |b| {
+                let mut lhs = random_polynomial(polynomial_size, 0);
+                b.iter(|| wrapping_sub_scalar(&mut lhs, &rhs));
+            },
+        );
+    }
+}
+
+pub fn batch_fft_benchmark(c: &mut Criterion) {
+    let polynomial_size = 1024;
+    let count = 64;
+    let input: Vec<u64> = (0..count as u64)
+        .flat_map(|seed| random_polynomial(polynomial_size, seed))
+        .collect();
+    let input = PolynomialList::new(
+        input.as_slice(),
+        PolynomialSize(polynomial_size),
+        PolynomialCount(count),
+    );
+    let mut output = vec![0.0; FourierPolynomialList::<&[f64]>::data_len(polynomial_size, count)];
+
+    // one plan and one scratch buffer for the whole list
+    c.bench_function(
+        &format!("batch-forward-fft-{polynomial_size}x{count}"),
+        |b| {
+            let fft = Fft::new(polynomial_size);
+            let fft = fft.as_view();
+            let mut mem = GlobalMemBuffer::new(batch_forward_negacyclic_scratch(fft).unwrap());
+            b.iter(|| {
+                batch_forward_negacyclic(
+                    input.as_view(),
+                    FourierPolynomialList::new(
+                        output.as_mut_slice(),
+                        PolynomialSize(polynomial_size),
+                        PolynomialCount(count),
+                    ),
+                    fft,
+                    DynStack::new(&mut mem),
+                );
+            });
+        },
+    );
+
+    #[cfg(feature = "parallel")]
+    c.bench_function(
+        &format!("batch-forward-fft-par-{polynomial_size}x{count}"),
+        |b| {
+            let fft = Fft::new(polynomial_size);
+            b.iter(|| {
+                batch_forward_negacyclic_par(
+                    input.as_view(),
+                    FourierPolynomialList::new(
+                        output.as_mut_slice(),
+                        PolynomialSize(polynomial_size),
+                        PolynomialCount(count),
+                    ),
+                    fft.as_view(),
+                );
+            });
+        },
+    );
+
+    // the plan and the scratch buffer are looked up again for each polynomial
+    c.bench_function(
+        &format!("single-forward-fft-{polynomial_size}x{count}"),
+        |b| {
+            b.iter(|| {
+                let output = output.chunks_exact_mut(polynomial_size);
+                for (standard, fourier) in input.iter_polynomial().zip(output) {
+                    let fft = Fft::new(polynomial_size);
+                    let fft = fft.as_view();
+                    let mut mem =
+                        GlobalMemBuffer::new(batch_forward_negacyclic_scratch(fft).unwrap());
+                    FourierPolynomial::new(fourier, polynomial_size).forward_negacyclic_as_torus(
+                        standard,
+                        fft,
+                        DynStack::new(&mut mem),
+                    );
+                }
+            });
+        },
+    );
+}
+
+pub fn mul_benchmark(c: &mut Criterion) {
+    // the schoolbook, Karatsuba and FFT multiplications, to find where each one is the fastest
+    for polynomial_size in [32, 64, 128, 256, 512, 1024] {
+        let lhs = random_polynomial(polynomial_size, 0);
+        let rhs = random_polynomial(polynomial_size, 1);
+        let lhs = || Polynomial::new(lhs.as_slice(), polynomial_size);
+        let rhs = || Polynomial::new(rhs.as_slice(), polynomial_size);
+
+        c.bench_function(
+            &format!("polynomial-mul-schoolbook-{polynomial_size}"),
+            |b| {
+                let mut out = vec![0_u64; polynomial_size];
+                b.iter(|| {
+                    Polynomial::new(out.as_mut_slice(), polynomial_size)
+                        .update_with_wrapping_mul(lhs(), rhs());
+                });
+            },
+        );
+
+        c.bench_function(
+            &format!("polynomial-mul-karatsuba-{polynomial_size}"),
+            |b| {
+                let mut out = vec![0_u64; polynomial_size];
+                let mut workspace = Workspace::new();
+                b.iter(|| {
+                    Polynomial::new(out.as_mut_slice(), polynomial_size)
+                        .update_with_wrapping_karatsuba_mul_with_scratch(
+                            lhs(),
+                            rhs(),
+                            &mut workspace,
+                        );
+                });
+            },
+        );
+
+        c.bench_function(&format!("polynomial-mul-fft-{polynomial_size}"), |b| {
+            let mut out = vec![0_u64; polynomial_size];
+            let fft = Fft::new(polynomial_size);
+            let fft = fft.as_view();
+            let mut mem = GlobalMemBuffer::new(fft_mul_scratch(fft).unwrap());
+            b.iter(|| {
+                Polynomial::new(out.as_mut_slice(), polynomial_size).update_with_fft_mul(
+                    lhs(),
+                    rhs(),
+                    fft,
+                    DynStack::new(&mut mem),
+                );
+            });
+        });
+    }
+}
+
+// Each iteration allocates the output, multiplies into it, then adds `lhs`, as a hot inner loop
+// working on temporaries would.
+fn array_vs_vec_benchmark_for<const N: usize>(c: &mut Criterion) {
+    let lhs: [u64; N] = random_polynomial(N, 0).try_into().unwrap();
+    let rhs: [u64; N] = random_polynomial(N, 1).try_into().unwrap();
+
+    c.bench_function(&format!("array-polynomial-mul-add-{N}"), |b| {
+        let lhs = ArrayPolynomial::<N>::from_array(lhs);
+        let rhs = ArrayPolynomial::<N>::from_array(rhs);
+        b.iter(|| {
+            let mut out = ArrayPolynomial::<N>::from_array([0; N]);
+            out.as_mut_view()
+                .update_with_wrapping_mul(lhs.as_view(), rhs.as_view());
+            out.as_mut_view().update_with_wrapping_add(lhs.as_view());
+            out
+        });
+    });
+
+    c.bench_function(&format!("vec-polynomial-mul-add-{N}"), |b| {
+        let lhs = Polynomial::new(lhs.to_vec(), N);
+        let rhs = Polynomial::new(rhs.to_vec(), N);
+        b.iter(|| {
+            let mut out = Polynomial::new(vec![0_u64; N], N);
+            out.as_mut_view()
+                .update_with_wrapping_mul(lhs.as_view(), rhs.as_view());
+            out.as_mut_view().update_with_wrapping_add(lhs.as_view());
+            out
+        });
+    });
+}
+
+pub fn array_vs_vec_benchmark(c: &mut Criterion) {
+    array_vs_vec_benchmark_for::<4>(c);
+    array_vs_vec_benchmark_for::<16>(c);
+    array_vs_vec_benchmark_for::<64>(c);
+    array_vs_vec_benchmark_for::<256>(c);
+}
+
+pub fn fft_plan_benchmark(c: &mut Criterion) {
+    for polynomial_size in [1024, 4096] {
+        let input = random_polynomial(polynomial_size, 0);
+        let mut output = vec![0_u64; polynomial_size];
+        let mut fourier = vec![0.0; polynomial_size];
+
+        let mut round_trip = |fft: &Fft, mut stack: DynStack<'_>| {
+            let fft = fft.as_view();
+            let mut fourier = FourierPolynomial::new(fourier.as_mut_slice(), polynomial_size);
+            fourier.forward_negacyclic_as_torus(
+                Polynomial::new(input.as_slice(), polynomial_size),
+                fft,
+                stack.rb_mut(),
+            );
+            fourier.as_view().backward_negacyclic_as_torus(
+                Polynomial::new(output.as_mut_slice(), polynomial_size),
+                fft,
+                stack,
+            );
+        };
+
+        let fft = Fft::new(polynomial_size);
+        let scratch = StackReq::try_any_of([
+            fft.as_view().forward_scratch().unwrap(),
+            fft.as_view().backward_scratch().unwrap(),
+        ])
+        .unwrap();
+        let mut mem = GlobalMemBuffer::new(scratch);
+
+        c.bench_function(
+            &format!("fft-round-trip-reused-plan-{polynomial_size}"),
+            |b| {
+                b.iter(|| round_trip(&fft, DynStack::new(&mut mem)));
+            },
+        );
+
+        // `Fft::new` finds the plan in its cache, so this only adds the lookup
+        c.bench_function(
+            &format!("fft-round-trip-cached-plan-{polynomial_size}"),
+            |b| {
+                b.iter(|| round_trip(&Fft::new(polynomial_size), DynStack::new(&mut mem)));
+            },
+        );
+
+        // what building a fresh plan for every transform would add, as `Fft::new` does without
+        // the `std` feature
+        c.bench_function(&format!("fft-fresh-plan-setup-{polynomial_size}"), |b| {
+            b.iter(|| {
+                let n = polynomial_size / 2;
+                let twisties = Twisties::new(n);
+                let plan = Plan::new(
+                    n,
+                    Method::UserProvided {
+                        base_algo: FftAlgo::Dif4,
+                        base_n: n.min(512),
+                    },
+                );
+                (twisties, plan)
+            });
+        });
+    }
+}
+
+pub fn decompose_and_forward_benchmark(c: &mut Criterion) {
+    let polynomial_size = 1024;
+    let decomp_params = DecompParams {
+        level: 3,
+        base_log: 10,
+    };
+    let decomposer = SignedDecomposer::new(decomp_params);
+    let fft = Fft::new(polynomial_size);
+    let fft = fft.as_view();
+
+    let input = random_polynomial(polynomial_size, 0);
+    let input = || Polynomial::new(input.as_slice(), polynomial_size);
+    let mut out = vec![0.0; polynomial_size * decomp_params.level];
+    let mut mem = GlobalMemBuffer::new(
+        decompose_and_forward_scratch(polynomial_size, fft)
+            .unwrap()
+            .or(fft.forward_scratch().unwrap()),
+    );
+
+    // the digits of all the levels go through one scratch buffer, with no allocation
+    c.bench_function(
+        &format!("decompose-and-forward-fused-{polynomial_size}"),
+        |b| {
+            b.iter(|| {
+                decompose_and_forward(
+                    input(),
+                    &decomposer,
+                    fft,
+                    &mut FourierPolynomialList::new(
+                        out.as_mut_slice(),
+                        PolynomialSize(polynomial_size),
+                        PolynomialCount(decomp_params.level),
+                    ),
+                    DynStack::new(&mut mem),
+                );
+            });
+        },
+    );
+
+    // each level is materialized in its own `Vec` before being transformed
+    c.bench_function(
+        &format!("decompose-and-forward-separate-{polynomial_size}"),
+        |b| {
+            b.iter(|| {
+                let levels = decomposer.decompose_polynomial(input());
+                for (level, fourier) in levels.zip(out.chunks_exact_mut(polynomial_size)) {
+                    FourierPolynomial::new(fourier, polynomial_size).forward_negacyclic_as_integer(
+                        level.as_view(),
+                        fft,
+                        DynStack::new(&mut mem),
+                    );
+                }
+            });
+        },
+    );
+}
+
+criterion_group!(
+    benches,
+    add_sub_benchmark,
+    batch_fft_benchmark,
+    mul_benchmark,
+    array_vs_vec_benchmark,
+    fft_plan_benchmark,
+    decompose_and_forward_benchmark
+);
+criterion_main!(benches);
@@ -0,0 +1,51 @@
+//! Builds polynomial lists from arbitrary shapes and data, and checks that
+//! `PolynomialList::verify_invariants` never panics and agrees with the checked constructors.
+//!
+//! The input is a `u64` polynomial size, a `u64` count, then the coefficients as little-endian
+//! `u64`s, any trailing bytes being ignored.
+
+#![no_main]
+
+use concrete_cpu::fuzzing::{PolynomialCount, PolynomialList, PolynomialSize};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|bytes: &[u8]| {
+    if bytes.len() < 16 {
+        return;
+    }
+    let read_u64 = |bytes: &[u8]| u64::from_le_bytes(bytes.try_into().unwrap());
+    let polynomial_size = read_u64(&bytes[..8]) as usize;
+    let count = read_u64(&bytes[8..16]) as usize;
+    let data: Vec<u64> = bytes[16..].chunks_exact(8).map(read_u64).collect();
+
+    for list in [
+        PolynomialList::try_from_flat_slice(&data, polynomial_size),
+        PolynomialList::try_from_flat_slice_with_count(&data, count),
+    ]
+    .into_iter()
+    .flatten()
+    {
+        assert_eq!(list.verify_invariants(), Ok(()));
+    }
+
+    // an arbitrary, possibly corrupted, shape
+    let list = PolynomialList {
+        data: data.as_slice(),
+        polynomial_size,
+        count,
+    };
+    let verified = list.verify_invariants();
+    let constructed = PolynomialList::try_new(
+        data.as_slice(),
+        PolynomialSize(polynomial_size),
+        PolynomialCount(count),
+    );
+    assert_eq!(verified, constructed.map(|_| ()));
+    if polynomial_size != 0 {
+        let from_flat_slice = PolynomialList::try_from_flat_slice(&data, polynomial_size);
+        assert_eq!(
+            verified.is_ok(),
+            from_flat_slice.is_ok_and(|list| list.count == count),
+        );
+    }
+});
@@ -298,6 +298,11 @@ void concrete_cpu_negate_lwe_ciphertext_u64(uint64_t *ct_out,
                                             const uint64_t *ct_in,
                                             size_t lwe_dimension);
 
+void concrete_cpu_polynomial_wrapping_mul(uint64_t *out,
+                                          const uint64_t *lhs,
+                                          const uint64_t *rhs,
+                                          size_t polynomial_size);
+
 size_t concrete_cpu_secret_key_size_u64(size_t lwe_dimension);
 
 #ifdef __cplusplus
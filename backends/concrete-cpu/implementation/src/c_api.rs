@@ -1,10 +1,12 @@
 pub mod bootstrap;
 #[cfg(feature = "csprng")]
 pub mod csprng;
+#[cfg(feature = "csprng")]
 pub mod encrypt;
 pub mod fft;
 pub mod keyswitch;
 pub mod linear_op;
+pub mod polynomial;
 pub mod secret_key;
 pub mod types;
 pub mod wop_pbs;
@@ -27,6 +29,8 @@ mod utils {
         val
     }
 
+    // libc does not define `size_t` for `wasm32-unknown-unknown`, which has no C runtime.
+    #[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
     const __ASSERT_USIZE_SAME_AS_SIZE_T: () = {
         let _: libc::size_t = 0_usize;
     };
@@ -53,6 +53,14 @@ pub unsafe extern "C" fn concrete_cpu_init_lwe_bootstrap_key_u64(
                 variance,
                 CsprngMut::new(csprng, csprng_vtable),
             ),
+            #[cfg(not(feature = "parallel"))]
+            Parallelism::Rayon => bsk.fill_with_new_key(
+                lwe_sk,
+                glwe_sk,
+                variance,
+                CsprngMut::new(csprng, csprng_vtable),
+            ),
+            #[cfg(feature = "parallel")]
             Parallelism::Rayon => bsk.fill_with_new_key_par(
                 lwe_sk,
                 glwe_sk,
@@ -1,9 +1,10 @@
+#[cfg(feature = "std")]
 use std::io::Read;
 
 use super::types::{Csprng, CsprngVtable, Uint128};
 use concrete_csprng::generators::{RandomGenerator, SoftwareRandomGenerator as Generator};
 use concrete_csprng::seeders::Seed;
-use libc::c_int;
+use core::ffi::c_int;
 
 #[no_mangle]
 pub static CONCRETE_CSPRNG_VTABLE: CsprngVtable = CsprngVtable {
@@ -66,8 +67,13 @@ pub unsafe extern "C" fn concrete_cpu_destroy_concrete_csprng(mem: *mut Csprng)
 pub unsafe extern "C" fn concrete_cpu_crypto_secure_random_128(u128: *mut Uint128) -> c_int {
     let buf = &mut (*u128).little_endian_bytes[0..16];
 
+    #[cfg(all(any(target_arch = "x86_64", target_arch = "x86"), feature = "std"))]
+    let has_rdseed = is_x86_feature_detected!("rdseed");
+    #[cfg(all(any(target_arch = "x86_64", target_arch = "x86"), not(feature = "std")))]
+    let has_rdseed = cfg!(target_feature = "rdseed");
+
     #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
-    if is_x86_feature_detected!("rdseed") {
+    if has_rdseed {
         let mut rand: u64 = 0;
         loop {
             if core::arch::x86_64::_rdseed64_step(&mut rand) == 1 {
@@ -108,11 +114,15 @@ pub unsafe extern "C" fn concrete_cpu_crypto_secure_random_128(u128: *mut Uint12
             }
         }
     }
+    #[cfg(feature = "std")]
     if let Ok(mut random) = std::fs::File::open("/dev/random") {
         if let Ok(16) = random.read(buf) {
             return -1;
         }
     }
+    // Without `std` there is no OS entropy source to fall back on.
+    #[cfg(not(feature = "std"))]
+    let _ = buf;
 
     0
 }
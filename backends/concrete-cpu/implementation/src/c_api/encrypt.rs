@@ -2,7 +2,7 @@ use crate::c_api::csprng::CONCRETE_CSPRNG_VTABLE;
 use crate::c_api::types::Csprng;
 use crate::implementation::encrypt::fill_with_random_gaussian;
 use crate::implementation::types::CsprngMut;
-use std::slice;
+use core::slice;
 
 #[no_mangle]
 pub unsafe extern "C" fn concrete_cpu_fill_with_random_gaussian(
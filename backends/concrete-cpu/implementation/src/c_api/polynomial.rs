@@ -0,0 +1,71 @@
+use super::utils::nounwind;
+use crate::implementation::types::polynomial::Polynomial;
+use core::slice;
+
+/// Sets `out` to the product of `lhs` and `rhs` in the negacyclic ring `Z_q[X]/(X^N+1)`, with
+/// `q = 2^64` and `N = polynomial_size`.
+///
+/// # Safety
+///
+/// `out`, `lhs` and `rhs` must not be null, even when `polynomial_size` is zero.
+///
+/// `[out, out + polynomial_size[` must be a valid mutable range, and must not alias
+/// `[lhs, lhs + polynomial_size[` or `[rhs, rhs + polynomial_size[`, both of which must be valid
+/// ranges for reads.
+#[no_mangle]
+pub unsafe extern "C" fn concrete_cpu_polynomial_wrapping_mul(
+    out: *mut u64,
+    lhs: *const u64,
+    rhs: *const u64,
+    polynomial_size: usize,
+) {
+    nounwind(|| {
+        debug_assert!(!out.is_null());
+        debug_assert!(!lhs.is_null());
+        debug_assert!(!rhs.is_null());
+
+        let mut out = Polynomial::new(
+            slice::from_raw_parts_mut(out, polynomial_size),
+            polynomial_size,
+        );
+        let lhs = Polynomial::new(slice::from_raw_parts(lhs, polynomial_size), polynomial_size);
+        let rhs = Polynomial::new(slice::from_raw_parts(rhs, polynomial_size), polynomial_size);
+
+        out.update_with_wrapping_mul(lhs, rhs);
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wrapping_mul_matches_safe_path() {
+        let polynomial_size = 64;
+        let lhs: Vec<u64> = (0..polynomial_size as u64)
+            .map(|i| i.wrapping_mul(0x9e37_79b9_7f4a_7c15))
+            .collect();
+        let rhs: Vec<u64> = (0..polynomial_size as u64).map(|i| i * i + 3).collect();
+
+        let mut expected = vec![0; polynomial_size];
+        Polynomial::new(&mut *expected, polynomial_size).update_with_wrapping_mul(
+            Polynomial::new(&*lhs, polynomial_size),
+            Polynomial::new(&*rhs, polynomial_size),
+        );
+
+        // go through a C function pointer, as the bindings would
+        let mul: unsafe extern "C" fn(*mut u64, *const u64, *const u64, usize) =
+            concrete_cpu_polynomial_wrapping_mul;
+        let mut out = vec![0; polynomial_size];
+        unsafe {
+            mul(
+                out.as_mut_ptr(),
+                lhs.as_ptr(),
+                rhs.as_ptr(),
+                polynomial_size,
+            )
+        };
+
+        assert_eq!(out, expected);
+    }
+}
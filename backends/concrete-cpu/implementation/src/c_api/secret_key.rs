@@ -4,7 +4,7 @@ use crate::implementation::types::{
     CsprngMut, DecompParams, GgswCiphertext, GlweCiphertext, GlweParams, GlweSecretKey,
     LweCiphertext, LweSecretKey,
 };
-use std::slice;
+use core::slice;
 
 #[no_mangle]
 pub unsafe extern "C" fn concrete_cpu_secret_key_size_u64(lwe_dimension: usize) -> usize {
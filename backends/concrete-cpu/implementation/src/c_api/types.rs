@@ -56,4 +56,12 @@ pub mod tests {
             )
         }
     }
+
+    pub fn random_u64(csprng: &mut SoftwareRandomGenerator) -> u64 {
+        u64::from_le_bytes(core::array::from_fn(|_| csprng.next().unwrap()))
+    }
+
+    pub fn random_coefficients(csprng: &mut SoftwareRandomGenerator, len: usize) -> Vec<u64> {
+        (0..len).map(|_| random_u64(csprng)).collect()
+    }
 }
@@ -61,6 +61,14 @@ pub unsafe extern "C" fn concrete_cpu_init_lwe_circuit_bootstrap_private_functio
                 variance,
                 CsprngMut::new(csprng, csprng_vtable),
             ),
+            #[cfg(not(feature = "parallel"))]
+            Parallelism::Rayon => fpksk_list.fill_with_fpksk_for_circuit_bootstrap(
+                &input_key,
+                &output_key,
+                variance,
+                CsprngMut::new(csprng, csprng_vtable),
+            ),
+            #[cfg(feature = "parallel")]
             Parallelism::Rayon => fpksk_list.fill_with_fpksk_for_circuit_bootstrap_par(
                 &input_key,
                 &output_key,
@@ -293,8 +301,8 @@ pub unsafe extern "C" fn concrete_cpu_circuit_bootstrap_boolean_vertical_packing
 
         let luts = PolynomialList::new(
             slice::from_raw_parts(lut, lut_size * lut_count),
-            lut_size,
-            lut_count,
+            PolynomialSize(lut_size),
+            PolynomialCount(lut_count),
         );
 
         let fourier_bsk = BootstrapKey::<&[f64]>::from_raw_parts(
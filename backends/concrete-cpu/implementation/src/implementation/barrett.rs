@@ -0,0 +1,150 @@
+/// Reduction modulo an arbitrary non-zero `u64` modulus, replacing the division by a
+/// multiplication by a precomputed reciprocal.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct BarrettReducer {
+    modulus: u64,
+    // floor((2^128 - 1) / modulus), which is floor(2^128 / modulus) unless the modulus is a power
+    // of two, in which case it is one less
+    reciprocal: u128,
+}
+
+// high 128 bits of the 256-bit product a * b
+#[inline(always)]
+fn mul_high(a: u128, b: u128) -> u128 {
+    let (a_lo, a_hi) = (a as u64 as u128, a >> 64);
+    let (b_lo, b_hi) = (b as u64 as u128, b >> 64);
+
+    let lo_lo = a_lo * b_lo;
+    let lo_hi = a_lo * b_hi;
+    let hi_lo = a_hi * b_lo;
+    let hi_hi = a_hi * b_hi;
+
+    // sum of the terms of weight 2^64, which fits in 66 bits
+    let middle = (lo_lo >> 64) + (lo_hi as u64 as u128) + (hi_lo as u64 as u128);
+    hi_hi + (lo_hi >> 64) + (hi_lo >> 64) + (middle >> 64)
+}
+
+impl BarrettReducer {
+    /// # Panics
+    ///
+    /// Panics if `modulus` is zero.
+    pub fn new(modulus: u64) -> Self {
+        assert_ne!(modulus, 0, "the modulus must not be zero");
+        Self {
+            modulus,
+            reciprocal: u128::MAX / modulus as u128,
+        }
+    }
+
+    pub fn modulus(&self) -> u64 {
+        self.modulus
+    }
+
+    /// Returns `x mod modulus`, for any `x`.
+    #[inline]
+    pub fn reduce(&self, x: u128) -> u64 {
+        let modulus = self.modulus as u128;
+        // the reciprocal is at most one less than 2^128 / modulus, so the quotient is at most one
+        // less than the exact one and the remainder is in [0, 2 * modulus)
+        let quotient = mul_high(x, self.reciprocal);
+        let remainder = x - quotient * modulus;
+        if remainder >= modulus {
+            (remainder - modulus) as u64
+        } else {
+            remainder as u64
+        }
+    }
+
+    /// Returns `a * b mod modulus`, for any `a` and `b`.
+    #[inline]
+    pub fn mul_mod(&self, a: u64, b: u64) -> u64 {
+        self.reduce(a as u128 * b as u128)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::c_api::types::tests::random_u64;
+    use concrete_csprng::generators::{RandomGenerator, SoftwareRandomGenerator};
+    use concrete_csprng::seeders::Seed;
+
+    const MODULI: [u64; 12] = [
+        1,
+        2,
+        3,
+        17,
+        12289,
+        1 << 32,
+        4611686018427365377,
+        (1 << 63) - 25,
+        1 << 63,
+        (1 << 63) + 1,
+        u64::MAX - 58,
+        u64::MAX,
+    ];
+
+    #[test]
+    fn mul_high_matches_wide_product() {
+        assert_eq!(mul_high(u128::MAX, u128::MAX), u128::MAX - 1);
+        assert_eq!(mul_high(1 << 127, 2), 1);
+        assert_eq!(mul_high(u64::MAX as u128, u64::MAX as u128), 0);
+        assert_eq!(mul_high(u128::MAX, 1 << 64), u64::MAX as u128);
+    }
+
+    #[test]
+    fn mul_mod_random() {
+        let mut csprng = SoftwareRandomGenerator::new(Seed(0));
+
+        let mut moduli = MODULI.to_vec();
+        moduli.extend((0..20).map(|_| random_u64(&mut csprng).max(1)));
+
+        for modulus in moduli {
+            let reducer = BarrettReducer::new(modulus);
+            for _ in 0..1000 {
+                let (a, b) = (random_u64(&mut csprng), random_u64(&mut csprng));
+                assert_eq!(
+                    reducer.mul_mod(a, b),
+                    (a as u128 * b as u128 % modulus as u128) as u64,
+                    "{a} * {b} mod {modulus}",
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn reduce_near_overflow() {
+        for modulus in MODULI {
+            let reducer = BarrettReducer::new(modulus);
+            let m = modulus as u128;
+
+            let mut inputs = vec![
+                0,
+                1,
+                m - 1,
+                m,
+                m + 1,
+                (m - 1) * (m - 1),
+                m * (m - 1),
+                u64::MAX as u128 * u64::MAX as u128,
+                u128::MAX,
+                u128::MAX - 1,
+                u128::MAX / m * m,
+                u128::MAX / m * m - 1,
+                1 << 127,
+                (1 << 127) - 1,
+            ];
+            if let Some(square) = m.checked_mul(m) {
+                inputs.extend([square - 1, square]);
+            }
+
+            for x in inputs {
+                assert_eq!(reducer.reduce(x), (x % m) as u64, "{x} mod {modulus}");
+            }
+            assert_eq!(
+                reducer.mul_mod(modulus - 1, modulus - 1),
+                ((m - 1) * (m - 1) % m) as u64,
+            );
+        }
+    }
+}
@@ -7,7 +7,10 @@ use super::fft::FftView;
 use super::polynomial::{
     update_with_wrapping_monic_monomial_mul, update_with_wrapping_unit_monomial_div,
 };
+#[cfg(feature = "parallel")]
+use super::types::ciphertext_list::LweCiphertextList;
 use super::types::*;
+use super::workspace::Workspace;
 use super::zip_eq;
 
 impl<'a> BootstrapKey<&'a [f64]> {
@@ -37,6 +40,14 @@ impl<'a> BootstrapKey<&'a [f64]> {
         ])
     }
 
+    /// Rotates `lut` by `-b`, then by `a_i * s_i` for each mask element `a_i` of `lwe`, with one
+    /// cmux per GGSW of the bootstrap key. Every coefficient of `lwe` is first switched to the
+    /// modulus `2N`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the LWE dimension of `lwe` is not the input dimension of the key, i.e. the
+    /// number of GGSW ciphertexts it holds.
     pub fn blind_rotate(
         self,
         mut lut: GlweCiphertext<&mut [u64]>,
@@ -44,12 +55,22 @@ impl<'a> BootstrapKey<&'a [f64]> {
         fft: FftView<'_>,
         mut stack: DynStack<'_>,
     ) {
+        assert_eq!(
+            lwe.lwe_dimension, self.input_lwe_dimension,
+            "the LWE dimension must match the number of GGSW ciphertexts in the bootstrap key",
+        );
+        debug_assert_eq!(lut.glwe_params, self.glwe_params);
+
         let (lwe_body, lwe_mask) = lwe.into_data().split_last().unwrap();
 
         let lut_poly_size = lut.glwe_params.polynomial_size;
         let modulus_switched_body = pbs_modulus_switch(*lwe_body, lut_poly_size, 0, 0);
 
-        for polynomial in lut.as_mut_view().into_polynomial_list().iter_polynomial() {
+        for polynomial in lut
+            .as_mut_view()
+            .into_polynomial_list()
+            .iter_polynomial_mut()
+        {
             update_with_wrapping_unit_monomial_div(polynomial, modulus_switched_body);
         }
 
@@ -68,7 +89,11 @@ impl<'a> BootstrapKey<&'a [f64]> {
                 // We rotate ct_1 by performing ct_1 <- ct_1 * X^{modulus_switched_mask_element}
                 let modulus_switched_mask_element =
                     pbs_modulus_switch(*lwe_mask_element, lut_poly_size, 0, 0);
-                for polynomial in ct1.as_mut_view().into_polynomial_list().iter_polynomial() {
+                for polynomial in ct1
+                    .as_mut_view()
+                    .into_polynomial_list()
+                    .iter_polynomial_mut()
+                {
                     update_with_wrapping_monic_monomial_mul(
                         polynomial,
                         modulus_switched_mask_element,
@@ -105,6 +130,146 @@ impl<'a> BootstrapKey<&'a [f64]> {
             .as_view()
             .fill_lwe_with_sample_extraction(lwe_out, 0);
     }
+
+    /// Same as [`BootstrapKey::bootstrap`], with the scratch memory taken from `workspace`.
+    pub fn bootstrap_with_scratch(
+        self,
+        lwe_out: LweCiphertext<&mut [u64]>,
+        lwe_in: LweCiphertext<&[u64]>,
+        accumulator: GlweCiphertext<&[u64]>,
+        fft: FftView<'_>,
+        workspace: &mut Workspace,
+    ) {
+        let stack = workspace.stack(Self::bootstrap_scratch(self.glwe_params, fft).unwrap());
+        self.bootstrap(lwe_out, lwe_in, accumulator, fft, stack);
+    }
+}
+
+/// Keyswitches each ciphertext of `inputs` with `ksk`, then bootstraps it with `bsk` and
+/// `accumulator` into the ciphertext of the same index of `outputs`.
+///
+/// The ciphertexts are split in at most `threads` contiguous batches, each processed by one rayon
+/// task with its own [`Workspace`](super::workspace::Workspace). The keys are only read, so the
+/// results do not depend on `threads`.
+///
+/// # Panics
+///
+/// Panics if `threads` is zero, or if `inputs` and `outputs` do not hold the same number of
+/// ciphertexts.
+#[cfg(feature = "parallel")]
+pub fn batch_bootstrap(
+    inputs: LweCiphertextList<&[u64]>,
+    outputs: LweCiphertextList<&mut [u64]>,
+    ksk: LweKeyswitchKey<&[u64]>,
+    bsk: BootstrapKey<&[f64]>,
+    accumulator: GlweCiphertext<&[u64]>,
+    fft: FftView<'_>,
+    threads: usize,
+) {
+    use rayon::prelude::*;
+
+    assert!(threads > 0, "the number of threads must not be zero");
+    assert_eq!(
+        inputs.count, outputs.count,
+        "there must be as many outputs as inputs",
+    );
+    debug_assert_eq!(inputs.lwe_dimension, ksk.input_dimension);
+    debug_assert_eq!(ksk.output_dimension, bsk.input_lwe_dimension);
+    debug_assert_eq!(outputs.lwe_dimension, bsk.glwe_params.lwe_dimension());
+
+    if inputs.count == 0 {
+        return;
+    }
+
+    let (in_dimension, out_dimension) = (inputs.lwe_dimension, outputs.lwe_dimension);
+    let (in_size, out_size) = (inputs.lwe_size(), outputs.lwe_size());
+    let keyswitched_dimension = ksk.output_dimension;
+    let batch_size = inputs.count.div_ceil(threads);
+
+    let scratch = StackReq::try_all_of([
+        StackReq::try_new_aligned::<u64>(keyswitched_dimension + 1, CACHELINE_ALIGN).unwrap(),
+        BootstrapKey::bootstrap_scratch(bsk.glwe_params, fft).unwrap(),
+    ])
+    .unwrap();
+
+    inputs
+        .into_data()
+        .par_chunks(batch_size * in_size)
+        .zip_eq(outputs.into_data().par_chunks_mut(batch_size * out_size))
+        .for_each(|(inputs, outputs)| {
+            let mut workspace = Workspace::new();
+
+            for (input, output) in zip_eq(
+                inputs.chunks_exact(in_size),
+                outputs.chunks_exact_mut(out_size),
+            ) {
+                let stack = workspace.stack(scratch);
+                let (mut keyswitched, stack) =
+                    stack.make_aligned_with(keyswitched_dimension + 1, CACHELINE_ALIGN, |_| 0_u64);
+                let mut keyswitched = LweCiphertext::new(&mut *keyswitched, keyswitched_dimension);
+
+                ksk.keyswitch_ciphertext(
+                    keyswitched.as_mut_view(),
+                    LweCiphertext::new(input, in_dimension),
+                );
+                bsk.bootstrap(
+                    LweCiphertext::new(output, out_dimension),
+                    keyswitched.as_view(),
+                    accumulator,
+                    fft,
+                    stack,
+                );
+            }
+        });
+}
+
+/// Fills `accumulator` with the trivial encryption of the lookup table of `f`, to be used with
+/// [`BootstrapKey::bootstrap`].
+///
+/// The input and output messages are in `0..message_modulus * carry_modulus`, encoded in the most
+/// significant bits of the torus with one bit of padding, i.e. `m` is encoded as `m * delta` with
+/// `delta = 2^63 / (message_modulus * carry_modulus)`. The outputs of `f` are reduced modulo
+/// `message_modulus * carry_modulus`.
+///
+/// Each output is repeated over a window of `N / (message_modulus * carry_modulus)` coefficients
+/// of the body. The body is then rotated by half a window, so that inputs with a noise smaller than
+/// `delta / 2` in absolute value, up to the rounding of the modulus switch to `2N`, land in the
+/// window of their message. The first half window wraps
+/// around to the top of the polynomial, and is negated since `X^N = -1`.
+///
+/// # Panics
+///
+/// Panics if `message_modulus * carry_modulus` does not divide the polynomial size.
+pub fn build_accumulator(
+    accumulator: GlweCiphertext<&mut [u64]>,
+    f: impl Fn(u64) -> u64,
+    message_modulus: usize,
+    carry_modulus: usize,
+) {
+    let polynomial_size = accumulator.glwe_params.polynomial_size;
+    let modulus = message_modulus * carry_modulus;
+    assert_eq!(
+        polynomial_size % modulus,
+        0,
+        "the message space size {modulus} must divide the polynomial size {polynomial_size}",
+    );
+
+    let window = polynomial_size / modulus;
+    let delta = (1_u64 << 63) / modulus as u64;
+
+    let (mut mask, body) = accumulator.into_mask_and_body();
+    mask.fill_with_zero();
+
+    let body = body.into_data();
+    for (m, window) in body.chunks_exact_mut(window).enumerate() {
+        window.fill((f(m as u64) % modulus as u64).wrapping_mul(delta));
+    }
+
+    let half_window = window / 2;
+    for a in &mut body[..half_window] {
+        *a = a.wrapping_neg();
+    }
+    body.rotate_left(half_window);
 }
 
 /// This function switches modulus for a single coefficient of a ciphertext,
@@ -131,6 +296,30 @@ pub fn pbs_modulus_switch(
     output as usize
 }
 
+/// Switches every coefficient (mask and body) of `ct` from the modulus `2^64` to the modulus
+/// `2^log_modulus`, i.e. replaces `x` by the closest integer to `x / 2^(64 - log_modulus)`,
+/// reduced modulo `2^log_modulus`.
+///
+/// Ties are rounded up: `x` exactly halfway between two multiples of `2^(64 - log_modulus)`
+/// goes to the larger one. Every output coefficient lies in `[0, 2^log_modulus)`.
+///
+/// # Panics
+///
+/// Panics if `log_modulus` is not in `1..64`.
+pub fn lwe_modulus_switch(ct: LweCiphertext<&mut [u64]>, log_modulus: usize) {
+    assert!(
+        (1..u64::BITS as usize).contains(&log_modulus),
+        "log_modulus must be in 1..64, got {log_modulus}",
+    );
+    let mask = (1_u64 << log_modulus) - 1;
+    for x in ct.into_data() {
+        // keep one extra bit below the output precision, which decides the rounding
+        let shifted = *x >> (u64::BITS as usize - log_modulus - 1);
+        // `shifted + 1` would overflow when `log_modulus` is 63 and `shifted` is `u64::MAX`
+        *x = ((shifted >> 1) + (shifted & 1)) & mask;
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::mem::MaybeUninit;
@@ -173,8 +362,11 @@ mod tests {
 
         let mut bsk = vec![0_u64; bsk_len];
 
-        BootstrapKey::new(bsk.as_mut_slice(), glwe_params, in_dim, decomp_params)
-            .fill_with_new_key_par(in_sk, out_sk, key_variance, csprng);
+        let key = BootstrapKey::new(bsk.as_mut_slice(), glwe_params, in_dim, decomp_params);
+        #[cfg(feature = "parallel")]
+        key.fill_with_new_key_par(in_sk, out_sk, key_variance, csprng);
+        #[cfg(not(feature = "parallel"))]
+        key.fill_with_new_key(in_sk, out_sk, key_variance, csprng);
         let standard = BootstrapKey::new(bsk.as_slice(), glwe_params, in_dim, decomp_params);
 
         let mut bsk_f = vec![0.; bsk_len];
@@ -263,6 +455,281 @@ mod tests {
         }
     }
 
+    // Keys for a 600-dimensional input and a GLWE of dimension 1 and size 1024.
+    fn keyset_1024(csprng: CsprngMut) -> KeySet {
+        KeySet::new(
+            csprng,
+            600,
+            GlweParams {
+                dimension: 1,
+                polynomial_size: 1024,
+            },
+            DecompParams {
+                level: 3,
+                base_log: 10,
+            },
+            0.0000000000000000000001,
+        )
+    }
+
+    // Key switching from the output key of the bootstrap of `keyset` back to its input key.
+    fn ksk_for(keyset: &KeySet, csprng: CsprngMut) -> LweKeyswitchKey<Vec<u64>> {
+        LweKeyswitchKey::new_random(
+            csprng,
+            keyset.out_sk.as_view(),
+            keyset.in_sk.as_view(),
+            DecompParams {
+                level: 3,
+                base_log: 10,
+            },
+            0.0000000000000000000001,
+        )
+    }
+
+    #[test]
+    fn bootstrap_identity_lut() {
+        let mut csprng = SoftwareRandomGenerator::new(Seed(0));
+
+        let mut keyset = keyset_1024(to_generic(&mut csprng));
+        let glwe_params = keyset.glwe_params;
+
+        // one bit of padding, so the messages only cover half of the torus
+        let log2_precision = 3;
+        let precision = 1_u64 << log2_precision;
+        let delta = 1_u64 << (64 - log2_precision - 1);
+        let lut_case_size = glwe_params.polynomial_size as u64 / precision;
+
+        let raw_lut: Vec<u64> = (0..glwe_params.polynomial_size as u64)
+            .map(|_| 0)
+            .chain((0..precision).flat_map(|m| (0..lut_case_size).map(move |_| m * delta)))
+            .collect();
+
+        for message in 0..precision {
+            let image = keyset.bootstrap(
+                to_generic(&mut csprng),
+                message * delta + delta / 2,
+                0.0000000001,
+                GlweCiphertext::new(&raw_lut, glwe_params),
+            );
+            assert_eq!(image.wrapping_add(delta / 2) / delta, message);
+        }
+    }
+
+    #[test]
+    fn build_accumulator_windows() {
+        use super::build_accumulator;
+        use crate::implementation::polynomial::update_with_wrapping_unit_monomial_div;
+
+        let glwe_params = GlweParams {
+            dimension: 1,
+            polynomial_size: 64,
+        };
+        let (message_modulus, carry_modulus) = (2, 2);
+        let modulus = 4;
+        let delta = (1_u64 << 63) / modulus;
+        let f = |m: u64| (3 * m + 1) % modulus;
+
+        let mut accumulator = vec![1_u64; 2 * glwe_params.polynomial_size];
+        build_accumulator(
+            GlweCiphertext::new(accumulator.as_mut_slice(), glwe_params),
+            f,
+            message_modulus,
+            carry_modulus,
+        );
+        assert!(accumulator[..glwe_params.polynomial_size]
+            .iter()
+            .all(|a| *a == 0));
+
+        // the trivial equivalent of a blind rotation of the accumulator by the encrypted message,
+        // with a noise of a quarter of the encoding step on both sides
+        for m in 0..modulus {
+            for noise in [-(delta as i64) / 4, 0, delta as i64 / 4] {
+                let pt = (m * delta).wrapping_add(noise as u64);
+                let rotation = super::pbs_modulus_switch(pt, glwe_params.polynomial_size, 0, 0);
+
+                let mut rotated = accumulator.clone();
+                let mut glwe = GlweCiphertext::new(rotated.as_mut_slice(), glwe_params);
+                for polynomial in glwe
+                    .as_mut_view()
+                    .into_polynomial_list()
+                    .iter_polynomial_mut()
+                {
+                    update_with_wrapping_unit_monomial_div(polynomial, rotation);
+                }
+
+                let mut lwe = LweCiphertext::zero(glwe_params.lwe_dimension());
+                glwe.as_view()
+                    .fill_lwe_with_sample_extraction(lwe.as_mut_view(), 0);
+                assert_eq!(*lwe.data.last().unwrap(), f(m) * delta);
+            }
+        }
+    }
+
+    #[test]
+    fn bootstrap_built_accumulator() {
+        use super::build_accumulator;
+
+        let mut csprng = SoftwareRandomGenerator::new(Seed(0));
+
+        let mut keyset = keyset_1024(to_generic(&mut csprng));
+        let glwe_params = keyset.glwe_params;
+
+        let (message_modulus, carry_modulus) = (4, 2);
+        let modulus = 8;
+        let delta = (1_u64 << 63) / modulus;
+        let f = |m: u64| m * m;
+
+        let mut accumulator = vec![0_u64; 2 * glwe_params.polynomial_size];
+        build_accumulator(
+            GlweCiphertext::new(accumulator.as_mut_slice(), glwe_params),
+            f,
+            message_modulus,
+            carry_modulus,
+        );
+
+        for m in 0..modulus {
+            let image = keyset.bootstrap(
+                to_generic(&mut csprng),
+                m * delta,
+                0.0000000001,
+                GlweCiphertext::new(&accumulator, glwe_params),
+            );
+            assert_eq!(image.wrapping_add(delta / 2) / delta, f(m) % modulus);
+        }
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn batch_bootstrap_does_not_depend_on_threads() {
+        use super::{batch_bootstrap, build_accumulator, LweCiphertextList};
+
+        let mut csprng = SoftwareRandomGenerator::new(Seed(0));
+
+        let keyset = keyset_1024(to_generic(&mut csprng));
+        let glwe_params = keyset.glwe_params;
+        let big_dim = glwe_params.lwe_dimension();
+        let ksk = ksk_for(&keyset, to_generic(&mut csprng));
+
+        let (message_modulus, carry_modulus) = (4, 1);
+        let modulus = 4;
+        let delta = (1_u64 << 63) / modulus;
+        let f = |m: u64| m + 1;
+
+        let mut accumulator = vec![0_u64; 2 * glwe_params.polynomial_size];
+        build_accumulator(
+            GlweCiphertext::new(accumulator.as_mut_slice(), glwe_params),
+            f,
+            message_modulus,
+            carry_modulus,
+        );
+
+        let count = 5;
+        let mut inputs = vec![0_u64; (big_dim + 1) * count];
+        for (i, input) in inputs.chunks_exact_mut(big_dim + 1).enumerate() {
+            keyset.out_sk.as_view().encrypt_lwe(
+                LweCiphertext::new(input, big_dim),
+                (i as u64 % modulus) * delta,
+                0.0000000001,
+                to_generic(&mut csprng),
+            );
+        }
+
+        let run = |threads: usize| {
+            let mut outputs = vec![0_u64; (big_dim + 1) * count];
+            batch_bootstrap(
+                LweCiphertextList::new(inputs.as_slice(), big_dim, count),
+                LweCiphertextList::new(outputs.as_mut_slice(), big_dim, count),
+                ksk.as_view(),
+                keyset.fourier_bsk.as_view(),
+                GlweCiphertext::new(&accumulator, glwe_params),
+                keyset.fft.as_view(),
+                threads,
+            );
+            outputs
+        };
+
+        let single_threaded = run(1);
+        assert_eq!(run(3), single_threaded);
+        assert_eq!(run(8), single_threaded);
+
+        for (i, output) in single_threaded.chunks_exact(big_dim + 1).enumerate() {
+            let image = keyset
+                .out_sk
+                .as_view()
+                .decrypt_lwe(LweCiphertext::new(output, big_dim));
+            let m = i as u64 % modulus;
+            assert_eq!(image.wrapping_add(delta / 2) / delta, f(m) % modulus);
+        }
+    }
+
+    #[test]
+    #[should_panic = "the LWE dimension must match"]
+    fn blind_rotate_checks_lwe_dimension() {
+        let glwe_params = GlweParams {
+            dimension: 1,
+            polynomial_size: 16,
+        };
+        let decomp_params = DecompParams {
+            level: 1,
+            base_log: 4,
+        };
+        let in_dim = 2;
+        let bsk = vec![0.0; glwe_params.polynomial_size * 4 * in_dim * decomp_params.level];
+        let mut lut = vec![0_u64; 2 * glwe_params.polynomial_size];
+        let lwe = LweCiphertext::zero(in_dim + 1);
+
+        let fft = Fft::new(glwe_params.polynomial_size);
+        let mut stack = vec![MaybeUninit::new(0_u8); 100000];
+        BootstrapKey::new(bsk.as_slice(), glwe_params, in_dim, decomp_params).blind_rotate(
+            GlweCiphertext::new(lut.as_mut_slice(), glwe_params),
+            lwe.as_view(),
+            fft.as_view(),
+            DynStack::new(&mut stack),
+        );
+    }
+
+    #[test]
+    fn lwe_modulus_switch_rounds_half_up() {
+        use super::lwe_modulus_switch;
+
+        let log_modulus = 4;
+        let step = 1_u64 << 60;
+        let half = step / 2;
+
+        let mut data = vec![
+            0,
+            3 * step,
+            3 * step + half - 1,
+            // exactly halfway between 3 and 4
+            3 * step + half,
+            3 * step + half + 1,
+            15 * step + half - 1,
+            // halfway between 15 and 16 = 0 mod 16
+            15 * step + half,
+            u64::MAX,
+        ];
+        lwe_modulus_switch(LweCiphertext::new(data.as_mut_slice(), 7), log_modulus);
+        assert_eq!(data, [0, 3, 3, 4, 4, 15, 0, 0]);
+
+        let mut data = vec![u64::MAX >> 1, 1 << 63];
+        lwe_modulus_switch(LweCiphertext::new(data.as_mut_slice(), 1), 1);
+        assert_eq!(data, [1, 1]);
+
+        let mut data: Vec<u64> = (0..64).map(|i| 1 << i).chain([u64::MAX]).collect();
+        lwe_modulus_switch(LweCiphertext::new(data.as_mut_slice(), 64), 11);
+        assert!(data.iter().all(|&x| x < 1 << 11));
+    }
+
+    #[test]
+    fn lwe_modulus_switch_largest_modulus() {
+        use super::lwe_modulus_switch;
+
+        // the extra rounding bit is the lowest one, so nothing is shifted out
+        let mut data = vec![u64::MAX, (1 << 63) - 1, 2, 1, 0];
+        lwe_modulus_switch(LweCiphertext::new(data.as_mut_slice(), 4), 63);
+        assert_eq!(data, [0, 1 << 62, 1, 1, 0]);
+    }
+
     #[test]
     fn bootstrap_correctness() {
         let mut csprng = SoftwareRandomGenerator::new(Seed(0));
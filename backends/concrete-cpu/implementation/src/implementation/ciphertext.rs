@@ -2,8 +2,12 @@ use super::types::*;
 use super::Split;
 
 impl<'a> GlweCiphertext<&'a [u64]> {
+    /// Fills `lwe` with an encryption of the `n_th` coefficient of the message of `self`, under
+    /// the GLWE secret key seen as an LWE key of dimension `glwe_dimension * polynomial_size`.
     pub fn fill_lwe_with_sample_extraction(self, lwe: LweCiphertext<&mut [u64]>, n_th: usize) {
         let polynomial_size = self.glwe_params.polynomial_size;
+        debug_assert_eq!(lwe.lwe_dimension, self.glwe_params.lwe_dimension());
+        debug_assert!(n_th < polynomial_size);
 
         // We retrieve the bodies and masks of the two ciphertexts.
         let (lwe_body, lwe_mask) = lwe.into_data().split_last_mut().unwrap();
@@ -34,3 +38,34 @@ impl<'a> GlweCiphertext<&'a [u64]> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn extract(glwe: &[u64], glwe_params: GlweParams, n_th: usize) -> Vec<u64> {
+        let mut lwe = LweCiphertext::zero(glwe_params.lwe_dimension());
+        GlweCiphertext::new(glwe, glwe_params)
+            .fill_lwe_with_sample_extraction(lwe.as_mut_view(), n_th);
+        lwe.into_data()
+    }
+
+    #[test]
+    fn sample_extraction_first_and_last_coefficients() {
+        let glwe_params = GlweParams {
+            dimension: 2,
+            polynomial_size: 4,
+        };
+        // mask polynomials A_0 = 1 + 2X + 3X^2 + 4X^3 and A_1 = 5 + 6X + 7X^2 + 8X^3, then the body
+        let glwe = [1, 2, 3, 4, 5, 6, 7, 8, 10, 11, 12, 13];
+        let neg = |x: u64| x.wrapping_neg();
+
+        // the constant coefficient of A_i * S_i is a_0 s_0 - a_3 s_1 - a_2 s_2 - a_1 s_3
+        assert_eq!(
+            extract(&glwe, glwe_params, 0),
+            [1, neg(4), neg(3), neg(2), 5, neg(8), neg(7), neg(6), 10],
+        );
+        // the coefficient of degree N - 1 does not wrap around: a_3 s_0 + a_2 s_1 + a_1 s_2 + a_0 s_3
+        assert_eq!(extract(&glwe, glwe_params, 3), [4, 3, 2, 1, 8, 7, 6, 5, 13],);
+    }
+}
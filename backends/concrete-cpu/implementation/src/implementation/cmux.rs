@@ -3,6 +3,7 @@ use super::fft::FftView;
 use super::types::*;
 use super::zip_eq;
 use crate::implementation::external_product::external_product_scratch;
+use aligned_vec::CACHELINE_ALIGN;
 use dyn_stack::{DynStack, SizeOverflow, StackReq};
 
 /// Returns the required memory for [`cmux`].
@@ -14,6 +15,10 @@ pub fn cmux_scratch(
 }
 
 /// This cmux mutates both ct1 and ct0. The result is in ct0 after the method was called.
+///
+/// This is the in-place variant: the output aliases `ct0` (the ciphertext selected when the
+/// GGSW encrypts 0), and `ct1` is used as the scratch space for the difference `ct1 - ct0`. See
+/// [`cmux_into`] to keep both inputs intact.
 pub fn cmux(
     ct0: GlweCiphertext<&mut [u64]>,
     mut ct1: GlweCiphertext<&mut [u64]>,
@@ -26,3 +31,148 @@ pub fn cmux(
     }
     external_product(ct0, fourier_ggsw, ct1.as_view(), fft, stack);
 }
+
+/// Returns the required memory for [`cmux_into`].
+pub fn cmux_into_scratch(
+    ggsw_glwe_params: GlweParams,
+    fft: FftView<'_>,
+) -> Result<StackReq, SizeOverflow> {
+    StackReq::try_new_aligned::<u64>(
+        GlweCiphertext::<&[u64]>::data_len(ggsw_glwe_params),
+        CACHELINE_ALIGN,
+    )?
+    .try_and(external_product_scratch(ggsw_glwe_params, fft)?)
+}
+
+/// Sets `out` to `ct_false + fourier_ggsw ⊠ (ct_true - ct_false)`, i.e. an encryption of the
+/// message of `ct_false` if `fourier_ggsw` encrypts 0, and of the message of `ct_true` if it
+/// encrypts 1.
+///
+/// Unlike [`cmux`], neither input is modified: the difference is computed in a scratch GLWE
+/// taken from `stack`. `out` cannot alias either input, use [`cmux`] to overwrite `ct_false`.
+pub fn cmux_into(
+    mut out: GlweCiphertext<&mut [u64]>,
+    ct_false: GlweCiphertext<&[u64]>,
+    ct_true: GlweCiphertext<&[u64]>,
+    fourier_ggsw: GgswCiphertext<&[f64]>,
+    fft: FftView<'_>,
+    stack: DynStack<'_>,
+) {
+    debug_assert_eq!(out.glwe_params, ct_false.glwe_params);
+    debug_assert_eq!(out.glwe_params, ct_true.glwe_params);
+
+    let (mut difference, stack) = stack.collect_aligned(
+        CACHELINE_ALIGN,
+        zip_eq(ct_true.into_data(), ct_false.into_data()).map(|(c1, c0)| c1.wrapping_sub(*c0)),
+    );
+    let difference = GlweCiphertext::new(&mut *difference, out.glwe_params);
+
+    out.as_mut_view()
+        .into_data()
+        .copy_from_slice(ct_false.into_data());
+    external_product(out, fourier_ggsw, difference.as_view(), fft, stack);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::c_api::types::tests::to_generic;
+    use crate::implementation::fft::Fft;
+    use concrete_csprng::generators::{RandomGenerator, SoftwareRandomGenerator};
+    use concrete_csprng::seeders::Seed;
+    use dyn_stack::{GlobalMemBuffer, ReborrowMut};
+
+    #[test]
+    fn cmux_selects_trivial_constants() {
+        let mut csprng = SoftwareRandomGenerator::new(Seed(0));
+
+        let glwe_params = GlweParams {
+            dimension: 1,
+            polynomial_size: 256,
+        };
+        let decomp_params = DecompParams {
+            level: 3,
+            base_log: 7,
+        };
+        let glwe_len = GlweCiphertext::<&[u64]>::data_len(glwe_params);
+        let ggsw_len = GgswCiphertext::<&[u64]>::data_len(glwe_params, decomp_params.level);
+        let variance = 2.0_f64.powi(-100);
+        let delta = 1_u64 << 60;
+
+        let sk = LweSecretKey::new_random(to_generic(&mut csprng), glwe_params.lwe_dimension());
+        let sk = GlweSecretKey::new(sk.data.as_slice(), glwe_params);
+
+        let fft = Fft::new(glwe_params.polynomial_size);
+        let fft = fft.as_view();
+        let mut mem = GlobalMemBuffer::new(
+            StackReq::try_any_of([
+                cmux_into_scratch(glwe_params, fft).unwrap(),
+                GgswCiphertext::fill_with_forward_fourier_scratch(fft).unwrap(),
+            ])
+            .unwrap(),
+        );
+        let mut stack = DynStack::new(&mut mem);
+
+        // trivial encryptions of constant polynomials: zero mask, message in the body
+        let trivial = |constant: u64| {
+            let mut data = vec![0_u64; glwe_len];
+            data[glwe_params.dimension * glwe_params.polynomial_size] = constant * delta;
+            data
+        };
+        let ct_false = trivial(3);
+        let ct_true = trivial(5);
+
+        for bit in [0_u64, 1] {
+            let mut ggsw = vec![0_u64; ggsw_len];
+            sk.encrypt_constant_ggsw(
+                GgswCiphertext::new(ggsw.as_mut_slice(), glwe_params, decomp_params),
+                bit,
+                variance,
+                to_generic(&mut csprng),
+            );
+            let mut fourier_ggsw = vec![0.0_f64; ggsw_len];
+            GgswCiphertext::new(fourier_ggsw.as_mut_slice(), glwe_params, decomp_params)
+                .fill_with_forward_fourier(
+                    GgswCiphertext::new(ggsw.as_slice(), glwe_params, decomp_params),
+                    fft,
+                    stack.rb_mut(),
+                );
+            let fourier_ggsw =
+                GgswCiphertext::new(fourier_ggsw.as_slice(), glwe_params, decomp_params);
+
+            let expected = if bit == 0 { 3 } else { 5 };
+            let decode = |out: &[u64]| {
+                let decrypted = sk.decrypt_glwe(GlweCiphertext::new(out, glwe_params));
+                decrypted
+                    .iter()
+                    .map(|d| d.wrapping_add(delta / 2) / delta)
+                    .collect::<Vec<_>>()
+            };
+            let mut expected_polynomial = vec![0; glwe_params.polynomial_size];
+            expected_polynomial[0] = expected;
+
+            let mut out = vec![0_u64; glwe_len];
+            cmux_into(
+                GlweCiphertext::new(out.as_mut_slice(), glwe_params),
+                GlweCiphertext::new(ct_false.as_slice(), glwe_params),
+                GlweCiphertext::new(ct_true.as_slice(), glwe_params),
+                fourier_ggsw,
+                fft,
+                stack.rb_mut(),
+            );
+            assert_eq!(decode(&out), expected_polynomial);
+
+            // the in-place variant agrees, writing the result over `ct0`
+            let mut ct0 = ct_false.clone();
+            let mut ct1 = ct_true.clone();
+            cmux(
+                GlweCiphertext::new(ct0.as_mut_slice(), glwe_params),
+                GlweCiphertext::new(ct1.as_mut_slice(), glwe_params),
+                fourier_ggsw,
+                fft,
+                stack.rb_mut(),
+            );
+            assert_eq!(decode(&ct0), expected_polynomial);
+        }
+    }
+}
@@ -1,4 +1,7 @@
+use alloc::vec::Vec;
+
 use super::decomposition::SignedDecompositionIter;
+use super::types::polynomial::Polynomial;
 use super::types::DecompParams;
 
 #[derive(Copy, Clone, Debug)]
@@ -44,4 +47,79 @@ impl SignedDecomposer {
         // not rounded to the closest representable first. We then perform it before decomposing.
         SignedDecompositionIter::new(self.closest_representable(input), self.decomp_params)
     }
+
+    /// Decomposes every coefficient of `input`, and yields one polynomial per decomposition
+    /// level, holding the signed digits of that level.
+    ///
+    /// # Warning
+    ///
+    /// Like [`SignedDecomposer::decompose`], the levels are yielded in reverse order, starting
+    /// from the highest level.
+    pub fn decompose_polynomial(
+        &self,
+        input: Polynomial<&[u64]>,
+    ) -> impl Iterator<Item = Polynomial<Vec<u64>>> {
+        let polynomial_size = input.polynomial_size;
+        let mut decompositions: Vec<SignedDecompositionIter> =
+            input.iter().map(|a| self.decompose(*a)).collect();
+
+        (0..self.decomp_params.level).map(move |_| {
+            Polynomial::new(
+                decompositions
+                    .iter_mut()
+                    .map(|decomposition| decomposition.next().unwrap().value())
+                    .collect(),
+                polynomial_size,
+            )
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::implementation::zip_eq;
+
+    #[test]
+    fn decompose_polynomial_recomposes() {
+        let decomp_params = DecompParams {
+            level: 3,
+            base_log: 10,
+        };
+        let decomposer = SignedDecomposer::new(decomp_params);
+
+        let input = [
+            0,
+            1,
+            u64::MAX,
+            1 << 63,
+            (1 << 63) - 1,
+            // the rounding carry propagates through all the levels
+            (1 << 54) - (1 << 33),
+            0x0123_4567_89ab_cdef,
+            0xfedc_ba98_7654_3210,
+        ];
+        let input = Polynomial::new(input.as_slice(), input.len());
+
+        let mut recomposed = vec![0_u64; input.polynomial_size];
+        let mut level_count = 0;
+        for (polynomial, level) in zip_eq(
+            decomposer.decompose_polynomial(input.as_ref()),
+            (1..=3).rev(),
+        ) {
+            level_count += 1;
+            let shift = u64::BITS as usize - decomp_params.base_log * level;
+            for (r, digit) in zip_eq(&mut recomposed, polynomial.into_data()) {
+                // digits are balanced, in [-B/2, B/2]
+                let half_base = 1_i64 << (decomp_params.base_log - 1);
+                assert!((digit as i64).abs() <= half_base);
+                *r = r.wrapping_add(digit << shift);
+            }
+        }
+        assert_eq!(level_count, decomp_params.level);
+
+        for (r, a) in zip_eq(recomposed, input.iter()) {
+            assert_eq!(r, decomposer.closest_representable(*a));
+        }
+    }
 }
@@ -1,13 +1,18 @@
+use alloc::vec::Vec;
+
 use super::decomposition::DecompositionTerm;
 use super::fpks::LweKeyBitDecomposition;
 use super::polynomial::{update_with_wrapping_add_mul, update_with_wrapping_sub_mul};
 use super::types::polynomial::Polynomial;
 use super::types::*;
 use super::{from_torus, zip_eq};
+#[cfg(feature = "parallel")]
+use core::cmp::Ordering;
 use core::slice;
+#[cfg(feature = "parallel")]
 use rayon::prelude::{IndexedParallelIterator, ParallelIterator};
+#[cfg(feature = "parallel")]
 use rayon::slice::ParallelSliceMut;
-use std::cmp::Ordering;
 
 pub fn mask_bytes_per_coef() -> usize {
     u64::BITS as usize / 8
@@ -100,10 +105,12 @@ pub fn noise_bytes_per_pfpksk(
     (lwe_dimension + 1) * noise_bytes_per_pfpksk_chunk(decomposition_level_count, polynomial_size)
 }
 
+/// Fills `buffer` with uniform values, drawing the 8 little-endian bytes of each element from
+/// `csprng`, in order.
 pub fn fill_with_random_uniform(buffer: &mut [u64], mut csprng: CsprngMut<'_, '_>) {
     #[cfg(target_endian = "little")]
     {
-        let len = std::mem::size_of_val(buffer);
+        let len = core::mem::size_of_val(buffer);
         let random_bytes = csprng
             .as_mut()
             .next_bytes(unsafe { slice::from_raw_parts_mut(buffer.as_mut_ptr() as _, len) });
@@ -137,8 +144,13 @@ fn random_gaussian_pair(variance: f64, mut csprng: CsprngMut<'_, '_>) -> (f64, f
     }
 }
 
+/// Fills `buffer` with Gaussian values of variance `variance` on the torus, in order.
+///
+/// The values are sampled in pairs with the polar Box-Muller method, each attempt drawing two
+/// uniform `u64` from `csprng`, so the number of bytes drawn depends on the rejections. It is the
+/// same for the same state of `csprng`.
 pub fn fill_with_random_gaussian(buffer: &mut [u64], variance: f64, mut csprng: CsprngMut<'_, '_>) {
-    for chunk in buffer.chunks_exact_mut(2) {
+    for chunk in buffer.chunks_mut(2) {
         let (g0, g1) = random_gaussian_pair(variance, csprng.as_mut());
         if let Some(first) = chunk.get_mut(0) {
             *first = from_torus(g0);
@@ -269,7 +281,7 @@ impl PackingKeyswitchKey<&mut [u64]> {
 
         // add minus one for the function which will be applied to the decomposed body
         // ( Scalar::MAX = -Scalar::ONE )
-        let input_key_bit_iter = input_lwe_key.data.iter().chain(std::iter::once(&u64::MAX));
+        let input_key_bit_iter = input_lwe_key.data.iter().chain(core::iter::once(&u64::MAX));
 
         // loop over the before key blocks
         for (&input_key_bit, keyswitch_key_block) in
@@ -310,8 +322,24 @@ impl PackingKeyswitchKey<&mut [u64]> {
             }
         }
     }
+
+    pub fn bit_decomp_iter_mut(
+        &mut self,
+    ) -> impl Iterator<Item = LweKeyBitDecomposition<&mut [u64]>> {
+        let glwe_params = self.glwe_params;
+
+        let level = self.decomp_params.level;
+
+        let chunks_size = level * (glwe_params.dimension + 1) * glwe_params.polynomial_size;
+
+        self.as_mut_view()
+            .into_data()
+            .chunks_exact_mut(chunks_size)
+            .map(move |sub| LweKeyBitDecomposition::new(sub, glwe_params, level))
+    }
 }
 
+#[cfg(feature = "parallel")]
 impl<'a> PackingKeyswitchKey<&'a mut [u64]> {
     pub fn fill_with_private_functional_packing_keyswitch_key_par(
         &'a mut self,
@@ -387,21 +415,6 @@ impl<'a> PackingKeyswitchKey<&'a mut [u64]> {
                 }
             });
     }
-
-    pub fn bit_decomp_iter_mut(
-        &mut self,
-    ) -> impl Iterator<Item = LweKeyBitDecomposition<&mut [u64]>> {
-        let glwe_params = self.glwe_params;
-
-        let level = self.decomp_params.level;
-
-        let chunks_size = level * (glwe_params.dimension + 1) * glwe_params.polynomial_size;
-
-        self.as_mut_view()
-            .into_data()
-            .chunks_exact_mut(chunks_size)
-            .map(move |sub| LweKeyBitDecomposition::new(sub, glwe_params, level))
-    }
 }
 
 #[cfg(feature = "parallel")]
@@ -493,6 +506,11 @@ impl GlweSecretKey<&[u64]> {
         }
     }
 
+    /// Fills `encrypted` with an encryption of zero.
+    ///
+    /// The randomness is drawn from `csprng` in a fixed order: all the mask coefficients first,
+    /// polynomial after polynomial, then the noise of the body coefficients. A generator seeded
+    /// identically thus gives a bit-identical ciphertext.
     pub fn encrypt_zero_glwe(
         self,
         encrypted: GlweCiphertext<&mut [u64]>,
@@ -619,6 +637,11 @@ impl GlweSecretKey<&[u64]> {
 }
 
 impl LweSecretKey<&[u64]> {
+    /// Fills `encrypted` with an encryption of `plaintext`.
+    ///
+    /// The randomness is drawn from `csprng` in a fixed order: the mask coefficients first, then
+    /// the noise of the body. A generator seeded identically thus gives a bit-identical
+    /// ciphertext.
     pub fn encrypt_lwe(
         self,
         encrypted: LweCiphertext<&mut [u64]>,
@@ -650,10 +673,47 @@ impl LweSecretKey<&[u64]> {
 #[cfg(test)]
 mod tests {
     use crate::c_api::types::tests::to_generic;
-    use crate::implementation::types::{CsprngMut, LweCiphertext, LweSecretKey};
+    use crate::implementation::types::{
+        CsprngMut, GlweCiphertext, GlweParams, GlweSecretKey, LweCiphertext, LweSecretKey,
+    };
     use concrete_csprng::generators::{RandomGenerator, SoftwareRandomGenerator};
     use concrete_csprng::seeders::Seed;
 
+    #[test]
+    fn encryption_is_deterministic_given_the_seed() {
+        let dim = 512;
+        let glwe_params = GlweParams {
+            dimension: 2,
+            polynomial_size: 256,
+        };
+        let sk =
+            LweSecretKey::new_random(to_generic(&mut SoftwareRandomGenerator::new(Seed(0))), dim);
+
+        let encrypt = |seed: u128| {
+            let mut csprng = SoftwareRandomGenerator::new(Seed(seed));
+
+            let mut lwe = LweCiphertext::zero(dim);
+            sk.as_view().encrypt_lwe(
+                lwe.as_mut_view(),
+                42 << 58,
+                0.0000000001,
+                to_generic(&mut csprng),
+            );
+
+            let mut glwe = vec![0_u64; GlweCiphertext::<&[u64]>::data_len(glwe_params)];
+            GlweSecretKey::new(sk.data.as_slice(), glwe_params).encrypt_zero_glwe(
+                GlweCiphertext::new(glwe.as_mut_slice(), glwe_params),
+                0.0000000001,
+                to_generic(&mut csprng),
+            );
+
+            (lwe.into_data(), glwe)
+        };
+
+        assert_eq!(encrypt(1), encrypt(1));
+        assert_ne!(encrypt(1), encrypt(2));
+    }
+
     fn encrypt_decrypt(
         mut csprng: CsprngMut,
         pt: u64,
@@ -684,4 +744,15 @@ mod tests {
             assert!((diff as f64).abs() / 2.0_f64.powi(64) < 0.0001);
         }
     }
+
+    #[test]
+    fn fill_with_random_gaussian_covers_odd_lengths() {
+        let mut csprng = SoftwareRandomGenerator::new(Seed(0));
+
+        for len in [1, 7, 513] {
+            let mut buffer = vec![0_u64; len];
+            super::fill_with_random_gaussian(&mut buffer, 0.01, to_generic(&mut csprng));
+            assert_ne!(*buffer.last().unwrap(), 0);
+        }
+    }
 }
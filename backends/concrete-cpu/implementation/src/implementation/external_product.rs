@@ -10,7 +10,10 @@ use crate::implementation::decomposition::TensorSignedDecompositionLendingIter;
 use crate::implementation::{assume_init_mut, Split};
 
 use super::fft::FftView;
+use super::types::fourier_polynomial_list::FourierPolynomialList;
+use super::types::polynomial::Polynomial;
 use super::types::*;
+use super::workspace::Workspace;
 use super::{as_mut_uninit, zip_eq};
 
 impl GgswCiphertext<&mut [f64]> {
@@ -40,6 +43,57 @@ impl GgswCiphertext<&mut [f64]> {
     }
 }
 
+/// Returns the required memory for [`decompose_and_forward`].
+pub fn decompose_and_forward_scratch(
+    polynomial_size: usize,
+    fft: FftView<'_>,
+) -> Result<StackReq, SizeOverflow> {
+    let standard_scratch = StackReq::try_new_aligned::<u64>(polynomial_size, CACHELINE_ALIGN)?;
+    StackReq::try_all_of([standard_scratch, standard_scratch, fft.forward_scratch()?])
+}
+
+/// Decomposes `input` with `decomposer` and fills each polynomial of `out` with the negacyclic
+/// FFT of the signed digits of one level, viewed as integers.
+///
+/// This is the same as transforming each polynomial yielded by
+/// [`SignedDecomposer::decompose_polynomial`], in the same order, i.e. starting from the highest
+/// level, but the digits of every level go through a single buffer taken from `stack`.
+pub fn decompose_and_forward(
+    input: Polynomial<&[u64]>,
+    decomposer: &SignedDecomposer,
+    fft: FftView<'_>,
+    out: &mut FourierPolynomialList<&mut [f64]>,
+    stack: DynStack<'_>,
+) {
+    let polynomial_size = input.polynomial_size;
+    debug_assert_eq!(out.polynomial_size, polynomial_size);
+    debug_assert_eq!(out.count, decomposer.decomp_params.level);
+
+    let (mut decomposition, stack) = TensorSignedDecompositionLendingIter::new(
+        input
+            .into_data()
+            .iter()
+            .map(|a| decomposer.closest_representable(*a)),
+        decomposer.decomp_params.base_log,
+        decomposer.decomp_params.level,
+        stack,
+    );
+    let (mut digits, mut stack) =
+        stack.make_aligned_with(polynomial_size, CACHELINE_ALIGN, |_| 0_u64);
+
+    for mut fourier in out.iter_polynomial_mut() {
+        let (_, _, level_digits) = decomposition.next_term().unwrap();
+        for (digit, level_digit) in zip_eq(digits.iter_mut(), level_digits) {
+            *digit = level_digit;
+        }
+        fourier.forward_negacyclic_as_integer(
+            Polynomial::new(&*digits, polynomial_size),
+            fft,
+            stack.rb_mut(),
+        );
+    }
+}
+
 /// Returns the required memory for [`external_product`].
 pub fn external_product_scratch(
     ggsw_glwe_params: GlweParams,
@@ -64,7 +118,11 @@ pub fn external_product_scratch(
     substack0.try_and(fourier_scratch)
 }
 
-/// Performs the external product of `ggsw` and `glwe`, and stores the result in `out`.
+/// Performs the external product of `ggsw` and `glwe`, and adds the result to `out`.
+///
+/// `ggsw` must be in the Fourier domain, see [`GgswCiphertext::fill_with_forward_fourier`]. If
+/// `ggsw` encrypts `m` and `glwe` encrypts the polynomial `p`, an encryption of `m * p` is added
+/// to `out`, so `out` should be zeroed first to get the product alone.
 pub fn external_product(
     mut out: GlweCiphertext<&mut [u64]>,
     ggsw: GgswCiphertext<&[f64]>,
@@ -169,6 +227,54 @@ pub fn external_product(
     }
 }
 
+/// Same as [`external_product`], with the scratch memory taken from `workspace`.
+pub fn external_product_with_scratch(
+    out: GlweCiphertext<&mut [u64]>,
+    ggsw: GgswCiphertext<&[f64]>,
+    glwe: GlweCiphertext<&[u64]>,
+    fft: FftView<'_>,
+    workspace: &mut Workspace,
+) {
+    let stack = workspace.stack(external_product_scratch(ggsw.glwe_params, fft).unwrap());
+    external_product(out, ggsw, glwe, fft, stack);
+}
+
+/// Performs the external product of `ggsw` with each ciphertext of `inputs`, and adds the result
+/// to the ciphertext of the same index of `outputs`, see [`external_product`].
+///
+/// The products are spread over rayon tasks, each of which allocates its own scratch memory.
+///
+/// # Panics
+///
+/// Panics if `outputs` and `inputs` do not have the same length.
+#[cfg(feature = "parallel")]
+pub fn batch_external_product(
+    outputs: &mut [GlweCiphertext<&mut [u64]>],
+    ggsw: GgswCiphertext<&[f64]>,
+    inputs: &[GlweCiphertext<&[u64]>],
+    fft: FftView<'_>,
+) {
+    use dyn_stack::GlobalMemBuffer;
+    use rayon::prelude::*;
+
+    assert_eq!(
+        outputs.len(),
+        inputs.len(),
+        "there must be as many outputs as inputs",
+    );
+    let scratch = external_product_scratch(ggsw.glwe_params, fft).unwrap();
+
+    outputs
+        .par_iter_mut()
+        .zip_eq(inputs.par_iter())
+        .for_each_init(
+            || GlobalMemBuffer::new(scratch),
+            |mem, (out, glwe)| {
+                external_product(out.as_mut_view(), ggsw, *glwe, fft, DynStack::new(mem));
+            },
+        );
+}
+
 #[cfg_attr(__profiling, inline(never))]
 fn collect_next_term<'a>(
     decomposition: &mut TensorSignedDecompositionLendingIter<'_>,
@@ -411,3 +517,214 @@ unsafe fn update_with_fmadd(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::c_api::types::tests::{random_coefficients, to_generic};
+    use crate::implementation::fft::Fft;
+    use concrete_csprng::generators::{RandomGenerator, SoftwareRandomGenerator};
+    use concrete_csprng::seeders::Seed;
+    use dyn_stack::GlobalMemBuffer;
+
+    #[test]
+    fn decompose_and_forward_matches_separate_steps() {
+        use crate::implementation::types::fourier_polynomial::FourierPolynomial;
+
+        let mut csprng = SoftwareRandomGenerator::new(Seed(0));
+
+        let polynomial_size = 512;
+        let decomp_params = DecompParams {
+            level: 4,
+            base_log: 6,
+        };
+        let decomposer = SignedDecomposer::new(decomp_params);
+        let fft = Fft::new(polynomial_size);
+        let fft = fft.as_view();
+
+        let input = random_coefficients(&mut csprng, polynomial_size);
+        let input = Polynomial::new(input.as_slice(), polynomial_size);
+
+        let mut fused = vec![0.0; polynomial_size * decomp_params.level];
+        let mut mem = GlobalMemBuffer::new(
+            decompose_and_forward_scratch(polynomial_size, fft)
+                .unwrap()
+                .or(fft.forward_scratch().unwrap()),
+        );
+        decompose_and_forward(
+            input.as_view(),
+            &decomposer,
+            fft,
+            &mut FourierPolynomialList::new(
+                fused.as_mut_slice(),
+                PolynomialSize(polynomial_size),
+                PolynomialCount(decomp_params.level),
+            ),
+            DynStack::new(&mut mem),
+        );
+
+        let mut separate = Vec::new();
+        for level in decomposer.decompose_polynomial(input) {
+            let mut fourier = FourierPolynomial::new(vec![0.0; polynomial_size], polynomial_size);
+            fourier.as_mut_view().forward_negacyclic_as_integer(
+                level.as_view(),
+                fft,
+                DynStack::new(&mut mem),
+            );
+            separate.extend_from_slice(&fourier.data);
+        }
+
+        assert_eq!(fused, separate);
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn batch_external_product_matches_sequential() {
+        let mut csprng = SoftwareRandomGenerator::new(Seed(0));
+
+        let glwe_params = GlweParams {
+            dimension: 2,
+            polynomial_size: 256,
+        };
+        let decomp_params = DecompParams {
+            level: 3,
+            base_log: 7,
+        };
+        let glwe_len = GlweCiphertext::<&[u64]>::data_len(glwe_params);
+        let ggsw_len = GgswCiphertext::<&[u64]>::data_len(glwe_params, decomp_params.level);
+
+        let sk = LweSecretKey::new_random(to_generic(&mut csprng), glwe_params.lwe_dimension());
+        let sk = GlweSecretKey::new(sk.data.as_slice(), glwe_params);
+
+        let fft = Fft::new(glwe_params.polynomial_size);
+        let fft = fft.as_view();
+        let mut mem = GlobalMemBuffer::new(
+            StackReq::try_any_of([
+                external_product_scratch(glwe_params, fft).unwrap(),
+                GgswCiphertext::fill_with_forward_fourier_scratch(fft).unwrap(),
+            ])
+            .unwrap(),
+        );
+        let mut stack = DynStack::new(&mut mem);
+
+        let mut ggsw = vec![0_u64; ggsw_len];
+        let mut ggsw = GgswCiphertext::new(ggsw.as_mut_slice(), glwe_params, decomp_params);
+        sk.encrypt_constant_ggsw(
+            ggsw.as_mut_view(),
+            1,
+            2.0_f64.powi(-100),
+            to_generic(&mut csprng),
+        );
+        let mut fourier_ggsw = vec![0.0_f64; ggsw_len];
+        let mut fourier_ggsw =
+            GgswCiphertext::new(fourier_ggsw.as_mut_slice(), glwe_params, decomp_params);
+        fourier_ggsw
+            .as_mut_view()
+            .fill_with_forward_fourier(ggsw.as_view(), fft, stack.rb_mut());
+
+        let count = 7;
+        let inputs = random_coefficients(&mut csprng, glwe_len * count);
+        let inputs: Vec<_> = inputs
+            .chunks_exact(glwe_len)
+            .map(|data| GlweCiphertext::new(data, glwe_params))
+            .collect();
+
+        // the products are added to the outputs, which start out non-zero
+        let initial: Vec<u64> = (0..(glwe_len * count) as u64).collect();
+
+        let mut sequential = initial.clone();
+        for (out, glwe) in zip_eq(sequential.chunks_exact_mut(glwe_len), &inputs) {
+            external_product(
+                GlweCiphertext::new(out, glwe_params),
+                fourier_ggsw.as_view(),
+                *glwe,
+                fft,
+                stack.rb_mut(),
+            );
+        }
+
+        let mut batched = initial;
+        let mut outputs: Vec<_> = batched
+            .chunks_exact_mut(glwe_len)
+            .map(|data| GlweCiphertext::new(data, glwe_params))
+            .collect();
+        batch_external_product(&mut outputs, fourier_ggsw.as_view(), &inputs, fft);
+
+        assert_eq!(batched, sequential);
+    }
+
+    #[test]
+    fn external_product_round_trip() {
+        let mut csprng = SoftwareRandomGenerator::new(Seed(0));
+
+        let glwe_params = GlweParams {
+            dimension: 1,
+            polynomial_size: 256,
+        };
+        let decomp_params = DecompParams {
+            level: 3,
+            base_log: 7,
+        };
+        let polynomial_size = glwe_params.polynomial_size;
+        let variance = 2.0_f64.powi(-100);
+        let message_bits = 4;
+        let delta = 1_u64 << (64 - message_bits);
+
+        let sk = LweSecretKey::new_random(to_generic(&mut csprng), glwe_params.lwe_dimension());
+        let sk = GlweSecretKey::new(sk.data.as_slice(), glwe_params);
+
+        let fft = Fft::new(polynomial_size);
+        let fft = fft.as_view();
+        let mut mem = GlobalMemBuffer::new(
+            StackReq::try_any_of([
+                external_product_scratch(glwe_params, fft).unwrap(),
+                GgswCiphertext::fill_with_forward_fourier_scratch(fft).unwrap(),
+            ])
+            .unwrap(),
+        );
+        let mut stack = DynStack::new(&mut mem);
+
+        let messages: Vec<u64> = (0..polynomial_size as u64)
+            .map(|i| i % (1 << message_bits))
+            .collect();
+
+        let mut glwe = vec![0_u64; GlweCiphertext::<&[u64]>::data_len(glwe_params)];
+        let mut glwe = GlweCiphertext::new(glwe.as_mut_slice(), glwe_params);
+        sk.encrypt_zero_glwe(glwe.as_mut_view(), variance, to_generic(&mut csprng));
+        for (b, m) in zip_eq(glwe.as_mut_view().into_body().into_data(), &messages) {
+            *b = b.wrapping_add(m * delta);
+        }
+
+        for bit in [0_u64, 1] {
+            let ggsw_len = GgswCiphertext::<&[u64]>::data_len(glwe_params, decomp_params.level);
+            let mut ggsw = vec![0_u64; ggsw_len];
+            let mut ggsw = GgswCiphertext::new(ggsw.as_mut_slice(), glwe_params, decomp_params);
+            sk.encrypt_constant_ggsw(ggsw.as_mut_view(), bit, variance, to_generic(&mut csprng));
+
+            let mut fourier_ggsw = vec![0.0_f64; ggsw_len];
+            let mut fourier_ggsw =
+                GgswCiphertext::new(fourier_ggsw.as_mut_slice(), glwe_params, decomp_params);
+            fourier_ggsw.as_mut_view().fill_with_forward_fourier(
+                ggsw.as_view(),
+                fft,
+                stack.rb_mut(),
+            );
+
+            let mut out = vec![0_u64; GlweCiphertext::<&[u64]>::data_len(glwe_params)];
+            let mut out = GlweCiphertext::new(out.as_mut_slice(), glwe_params);
+            external_product(
+                out.as_mut_view(),
+                fourier_ggsw.as_view(),
+                glwe.as_view(),
+                fft,
+                stack.rb_mut(),
+            );
+
+            let decrypted = sk.decrypt_glwe(out.as_view());
+            for (d, m) in zip_eq(decrypted, &messages) {
+                let decoded = (d.wrapping_add(delta / 2) / delta) % (1 << message_bits);
+                assert_eq!(decoded, bit * m);
+            }
+        }
+    }
+}
@@ -53,6 +53,11 @@ impl Twisties<ABox<[f64]>> {
 ///
 /// This structure contains the twisting factors as well as the
 /// FFT plan needed for the negacyclic convolution over the reals.
+///
+/// Both are computed once per polynomial size: with the `std` feature, [`Fft::new`] returns the
+/// plan cached for that size after the first call, and cloning only increments a reference count.
+/// An `Fft` is `Send + Sync`, so a single one can be shared by all the threads of a parallel
+/// routine.
 #[derive(Clone, Debug)]
 pub struct Fft {
     plan: Arc<(Twisties<ABox<[f64]>>, Plan)>,
@@ -66,6 +71,12 @@ pub struct FftView<'a> {
     pub twisties: Twisties<&'a [f64]>,
 }
 
+// the plan is shared between the rayon tasks of the parallel routines
+const _: fn() = || {
+    fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<Fft>();
+};
+
 impl Fft {
     #[inline]
     pub fn as_view(&self) -> FftView<'_> {
@@ -139,7 +150,7 @@ mod no_std {
     impl Fft {
         /// Real polynomial of size `size`.
         pub fn new(size: usize) -> Self {
-            let n = size.0;
+            let n = size;
             Self {
                 plan: Arc::new((
                     Twisties::new(n / 2),
@@ -147,7 +158,7 @@ mod no_std {
                         n / 2,
                         Method::UserProvided {
                             base_algo: FftAlgo::Dif4,
-                            base_n: 512,
+                            base_n: (n / 2).min(512),
                         },
                     ),
                 )),
@@ -155,3 +166,66 @@ mod no_std {
         }
     }
 }
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::implementation::types::fourier_polynomial::FourierPolynomial;
+    use crate::implementation::types::polynomial::Polynomial;
+    use dyn_stack::{DynStack, GlobalMemBuffer, ReborrowMut, StackReq};
+
+    #[test]
+    fn plans_are_cached() {
+        let fft = Fft::new(512);
+        assert!(Arc::ptr_eq(&fft.plan, &Fft::new(512).plan));
+        assert!(!Arc::ptr_eq(&fft.plan, &Fft::new(1024).plan));
+    }
+
+    #[test]
+    fn shared_plan_round_trip() {
+        let polynomial_size = 1024;
+        let fft = Fft::new(polynomial_size);
+
+        std::thread::scope(|scope| {
+            for thread in 0..4_u64 {
+                let fft = fft.as_view();
+                scope.spawn(move || {
+                    let mut mem = GlobalMemBuffer::new(
+                        StackReq::try_any_of([
+                            fft.forward_scratch().unwrap(),
+                            fft.backward_scratch().unwrap(),
+                        ])
+                        .unwrap(),
+                    );
+                    let mut stack = DynStack::new(&mut mem);
+                    let mut fourier =
+                        FourierPolynomial::new(vec![0.0; polynomial_size], polynomial_size);
+                    let mut output = vec![0_u64; polynomial_size];
+
+                    for i in 0..100_u64 {
+                        let input: Vec<u64> = (0..polynomial_size as u64)
+                            .map(|j| {
+                                (thread << 40 | i << 20 | j).wrapping_mul(0x9e37_79b9_7f4a_7c15)
+                            })
+                            .collect();
+
+                        fourier.as_mut_view().forward_negacyclic_as_torus(
+                            Polynomial::new(input.as_slice(), polynomial_size),
+                            fft,
+                            stack.rb_mut(),
+                        );
+                        fourier.as_view().backward_negacyclic_as_torus(
+                            Polynomial::new(output.as_mut_slice(), polynomial_size),
+                            fft,
+                            stack.rb_mut(),
+                        );
+
+                        for (a, b) in zip_eq(&output, &input) {
+                            assert!((a.wrapping_sub(*b) as i64).unsigned_abs() < 1 << 32);
+                        }
+                    }
+                });
+            }
+        });
+    }
+}
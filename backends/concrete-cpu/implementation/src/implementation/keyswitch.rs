@@ -44,6 +44,7 @@ impl LweKeyswitchKey<&[u64]> {
 mod tests {
     use crate::c_api::types::tests::to_generic;
     use crate::implementation::types::*;
+    use crate::implementation::zip_eq;
     use concrete_csprng::generators::{RandomGenerator, SoftwareRandomGenerator};
     use concrete_csprng::seeders::Seed;
 
@@ -67,16 +68,12 @@ mod tests {
 
             let out_sk = LweSecretKey::new_random(csprng.as_mut(), out_dim);
 
-            let ksk_len = (out_dim + 1) * in_dim * decomp_params.level;
-
-            let mut ksk =
-                LweKeyswitchKey::new(vec![0_u64; ksk_len], out_dim, in_dim, decomp_params);
-
-            ksk.as_mut_view().fill_with_keyswitch_key(
+            let ksk = LweKeyswitchKey::new_random(
+                csprng,
                 in_sk.as_view(),
                 out_sk.as_view(),
+                decomp_params,
                 key_variance,
-                csprng,
             );
 
             Self {
@@ -105,6 +102,62 @@ mod tests {
         }
     }
 
+    #[test]
+    fn keyswitch_after_sample_extraction() {
+        let mut csprng = SoftwareRandomGenerator::new(Seed(0));
+
+        let glwe_params = GlweParams {
+            dimension: 1,
+            polynomial_size: 128,
+        };
+        let polynomial_size = glwe_params.polynomial_size;
+        let big_dim = glwe_params.lwe_dimension();
+        let small_dim = 64;
+        let decomp_params = DecompParams {
+            level: 3,
+            base_log: 8,
+        };
+        let variance = 0.0000000000000000000001;
+
+        let glwe_sk = LweSecretKey::new_random(to_generic(&mut csprng), big_dim);
+        let small_sk = LweSecretKey::new_random(to_generic(&mut csprng), small_dim);
+        let ksk = LweKeyswitchKey::new_random(
+            to_generic(&mut csprng),
+            glwe_sk.as_view(),
+            small_sk.as_view(),
+            decomp_params,
+            variance,
+        );
+
+        // 3 bits of message, in the most significant bits
+        let delta = 1_u64 << 61;
+        let message: Vec<u64> = (0..polynomial_size as u64).map(|i| (i * 5) % 8).collect();
+
+        let mut glwe = vec![0_u64; GlweCiphertext::<&[u64]>::data_len(glwe_params)];
+        let mut glwe = GlweCiphertext::new(glwe.as_mut_slice(), glwe_params);
+        GlweSecretKey::new(glwe_sk.data.as_slice(), glwe_params).encrypt_zero_glwe(
+            glwe.as_mut_view(),
+            variance,
+            to_generic(&mut csprng),
+        );
+        for (b, m) in zip_eq(glwe.get_body_mut().into_data(), &message) {
+            *b = b.wrapping_add(m * delta);
+        }
+
+        for n_th in [0, 1, 77, polynomial_size - 1] {
+            let mut extracted = LweCiphertext::zero(big_dim);
+            glwe.as_view()
+                .fill_lwe_with_sample_extraction(extracted.as_mut_view(), n_th);
+
+            let mut switched = LweCiphertext::zero(small_dim);
+            ksk.as_view()
+                .keyswitch_ciphertext(switched.as_mut_view(), extracted.as_view());
+
+            let decrypted = small_sk.as_view().decrypt_lwe(switched.as_view());
+            assert_eq!(decrypted.wrapping_add(delta / 2) / delta % 8, message[n_th]);
+        }
+    }
+
     #[test]
     fn keyswitch_correctness() {
         let mut csprng = SoftwareRandomGenerator::new(Seed(0));
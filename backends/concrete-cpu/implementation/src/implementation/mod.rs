@@ -1,6 +1,8 @@
+use alloc::boxed::Box;
+use alloc::vec::Vec;
 use core::mem::MaybeUninit;
 
-use aligned_vec::{ABox, AVec, CACHELINE_ALIGN};
+use aligned_vec::{ABox, AVec, ConstAlign};
 
 #[allow(unused_macros)]
 macro_rules! izip {
@@ -39,14 +41,16 @@ macro_rules! izip {
     };
 }
 
+pub mod barrett;
 mod ciphertext;
 
 mod convert;
-mod decomposer;
+pub mod decomposer;
 mod decomposition;
 pub mod fft;
 pub mod fpks;
-mod polynomial;
+pub mod ntt;
+pub mod polynomial;
 
 pub mod types;
 
@@ -56,6 +60,7 @@ pub mod encrypt;
 pub mod external_product;
 pub mod keyswitch;
 pub mod wop;
+pub mod workspace;
 
 /// Convert a mutable slice reference to an uninitialized mutable slice reference.
 ///
@@ -122,6 +127,43 @@ pub trait Container: Sized + AsRef<[Self::Item]> {
     fn len(&self) -> usize {
         self.as_ref().len()
     }
+
+    /// Overwrites the elements with zeros, if the container owns them. Called when a
+    /// [`PolynomialList`](types::polynomial_list::PolynomialList) is dropped.
+    ///
+    /// Views keep the default, which does nothing, since they do not own the buffer they point
+    /// to.
+    ///
+    /// # Safety
+    ///
+    /// The elements may be left holding all-zero bytes, which need not be a valid
+    /// `Self::Item`, so they must not be read afterwards unless all-zero bytes are a valid
+    /// `Self::Item`. Only the `Drop` impl of `PolynomialList` calls this.
+    #[cfg(feature = "zeroize")]
+    unsafe fn zeroize_owned(&mut self) {}
+}
+
+/// Overwrites `data` with zero bytes, in a way that is not optimized out even though the buffer
+/// is about to be freed. Elements with drop glue are left untouched, since they are dropped
+/// after this.
+///
+/// # Safety
+///
+/// The elements must not be read afterwards, see [`Container::zeroize_owned`].
+#[cfg(feature = "zeroize")]
+unsafe fn zeroize_elements<T>(data: &mut [T]) {
+    use zeroize::Zeroize;
+
+    if core::mem::needs_drop::<T>() {
+        return;
+    }
+    // SAFETY: `MaybeUninit<T>` has the same layout as `T`, and any bytes are valid for it. The
+    // caller does not read the zeroed elements again, and dropping them does nothing, see above.
+    let data = core::slice::from_raw_parts_mut(
+        data.as_mut_ptr() as *mut core::mem::MaybeUninit<T>,
+        data.len(),
+    );
+    data.zeroize();
 }
 
 pub trait ContainerMut: Container + AsMut<[Self::Item]> {}
@@ -139,28 +181,75 @@ impl<'a, T> Container for &'a mut [T] {
 }
 impl<'a, T> ContainerMut for &'a mut [T] {}
 
-impl<T> Container for ABox<[T]> {
+/// Vector whose buffer is aligned to `ALIGN` bytes, e.g. for aligned SIMD loads in the FFT.
+///
+/// `ALIGN` must be a power of two. Its length and `Container::len` count elements, not bytes.
+pub type AlignedVec<const ALIGN: usize, T = u64> = AVec<T, ConstAlign<ALIGN>>;
+
+impl<T, const ALIGN: usize> Container for ABox<[T], ConstAlign<ALIGN>> {
+    type Item = T;
+
+    #[cfg(feature = "zeroize")]
+    unsafe fn zeroize_owned(&mut self) {
+        zeroize_elements(self.as_mut());
+    }
+}
+impl<T, const ALIGN: usize> ContainerMut for ABox<[T], ConstAlign<ALIGN>> {}
+impl<T, const ALIGN: usize> ContainerOwned for ABox<[T], ConstAlign<ALIGN>> {
+    fn collect(iter: impl Iterator<Item = Self::Item>) -> Self {
+        AVec::from_iter(ALIGN, iter).into_boxed_slice()
+    }
+}
+
+impl<T, const ALIGN: usize> Container for AVec<T, ConstAlign<ALIGN>> {
     type Item = T;
+
+    #[cfg(feature = "zeroize")]
+    unsafe fn zeroize_owned(&mut self) {
+        zeroize_elements(self.as_mut());
+    }
 }
-impl<T> ContainerMut for ABox<[T]> {}
-impl<T> ContainerOwned for ABox<[T]> {
+impl<T, const ALIGN: usize> ContainerMut for AVec<T, ConstAlign<ALIGN>> {}
+impl<T, const ALIGN: usize> ContainerOwned for AVec<T, ConstAlign<ALIGN>> {
     fn collect(iter: impl Iterator<Item = Self::Item>) -> Self {
-        AVec::from_iter(CACHELINE_ALIGN, iter).into_boxed_slice()
+        AVec::from_iter(ALIGN, iter)
+    }
+}
+
+/// Fixed-size arrays, e.g. for small polynomials that live on the stack, see
+/// [`types::polynomial::ArrayPolynomial`].
+impl<T, const N: usize> Container for [T; N] {
+    type Item = T;
+
+    #[cfg(feature = "zeroize")]
+    unsafe fn zeroize_owned(&mut self) {
+        zeroize_elements(self.as_mut());
     }
 }
+impl<T, const N: usize> ContainerMut for [T; N] {}
 
-impl<T> Container for AVec<T> {
+impl<T> Container for Box<[T]> {
     type Item = T;
+
+    #[cfg(feature = "zeroize")]
+    unsafe fn zeroize_owned(&mut self) {
+        zeroize_elements(self.as_mut());
+    }
 }
-impl<T> ContainerMut for AVec<T> {}
-impl<T> ContainerOwned for AVec<T> {
+impl<T> ContainerMut for Box<[T]> {}
+impl<T> ContainerOwned for Box<[T]> {
     fn collect(iter: impl Iterator<Item = Self::Item>) -> Self {
-        AVec::from_iter(CACHELINE_ALIGN, iter)
+        iter.collect()
     }
 }
 
 impl<T> Container for Vec<T> {
     type Item = T;
+
+    #[cfg(feature = "zeroize")]
+    unsafe fn zeroize_owned(&mut self) {
+        zeroize_elements(self.as_mut());
+    }
 }
 impl<T> ContainerMut for Vec<T> {}
 impl<T> ContainerOwned for Vec<T> {
@@ -169,6 +258,15 @@ impl<T> ContainerOwned for Vec<T> {
     }
 }
 
+/// Owned containers wrapped in [`zeroize::Zeroizing`] are overwritten with zeros when dropped,
+/// which makes them suitable for holding secret key material.
+#[cfg(feature = "zeroize")]
+impl<C: Container + zeroize::Zeroize> Container for zeroize::Zeroizing<C> {
+    type Item = C::Item;
+}
+#[cfg(feature = "zeroize")]
+impl<C: ContainerMut + zeroize::Zeroize> ContainerMut for zeroize::Zeroizing<C> {}
+
 pub trait Split: Container {
     type Pointer: Copy;
     type Chunks: DoubleEndedIterator<Item = Self> + ExactSizeIterator<Item = Self>;
@@ -0,0 +1,372 @@
+use alloc::vec::Vec;
+
+use super::barrett::BarrettReducer;
+use super::polynomial::bit_reverse_in_place;
+use super::types::polynomial::Polynomial;
+use super::workspace::Workspace;
+use super::zip_eq;
+
+/// Negacyclic number-theoretic transform modulo a prime `p`, which unlike the [`Fft`] is exact.
+///
+/// Multiplying two polynomials with coefficients in `[0, p)` through the transform gives their
+/// product in `Z_p[X]/(X^N+1)`. This is the product modulo `2^64` only if the coefficients of the
+/// exact product over the integers are in `[0, p)`.
+///
+/// The transform is the one of [Longa and Naehrig][paper], which merges the twist by the powers
+/// of a `2N`-th root of unity `psi` into the butterflies. The output of [`Ntt::forward`] is in
+/// bit-reversed order, which does not matter for pointwise products.
+///
+/// [`Fft`]: super::fft::Fft
+/// [paper]: https://eprint.iacr.org/2016/504
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Ntt {
+    modulus: u64,
+    polynomial_size: usize,
+    // powers of `psi` and `psi^-1` in bit-reversed order, with their Shoup precomputations
+    psi_rev: Vec<u64>,
+    psi_rev_shoup: Vec<u64>,
+    psi_inv_rev: Vec<u64>,
+    psi_inv_rev_shoup: Vec<u64>,
+    n_inv: u64,
+    n_inv_shoup: u64,
+    // for the pointwise products, whose operands are not known in advance
+    reducer: BarrettReducer,
+}
+
+// a * b mod p, for a and b in [0, p), for the precomputations only
+fn mul_mod(a: u64, b: u64, p: u64) -> u64 {
+    ((a as u128 * b as u128) % p as u128) as u64
+}
+
+fn pow_mod(mut base: u64, mut exponent: u64, p: u64) -> u64 {
+    let mut result = 1 % p;
+    while exponent != 0 {
+        if exponent & 1 == 1 {
+            result = mul_mod(result, base, p);
+        }
+        base = mul_mod(base, base, p);
+        exponent >>= 1;
+    }
+    result
+}
+
+// floor(w * 2^64 / p), for w in [0, p)
+fn shoup(w: u64, p: u64) -> u64 {
+    (((w as u128) << 64) / p as u128) as u64
+}
+
+// a * w mod p, for a in [0, 2^64) and w in [0, p), with p < 2^63, given w_shoup = shoup(w, p)
+#[inline(always)]
+fn mul_mod_shoup(a: u64, w: u64, w_shoup: u64, p: u64) -> u64 {
+    let q = ((a as u128 * w_shoup as u128) >> 64) as u64;
+    // the result is in [0, 2p)
+    let r = a.wrapping_mul(w).wrapping_sub(q.wrapping_mul(p));
+    if r >= p {
+        r - p
+    } else {
+        r
+    }
+}
+
+#[inline(always)]
+fn add_mod(a: u64, b: u64, p: u64) -> u64 {
+    let sum = a + b;
+    if sum >= p {
+        sum - p
+    } else {
+        sum
+    }
+}
+
+#[inline(always)]
+fn sub_mod(a: u64, b: u64, p: u64) -> u64 {
+    if a >= b {
+        a - b
+    } else {
+        a + p - b
+    }
+}
+
+impl Ntt {
+    /// Creates the transform of polynomials of size `polynomial_size` modulo the prime `modulus`,
+    /// where `psi` is a primitive `2 * polynomial_size`-th root of unity modulo `modulus`.
+    ///
+    /// The primality of `modulus` is not checked.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `polynomial_size` is not a power of two, if `modulus` is not odd and smaller than
+    /// `2^63`, or if `psi^polynomial_size` is not `-1` modulo `modulus`.
+    pub fn new(polynomial_size: usize, modulus: u64, psi: u64) -> Self {
+        assert!(polynomial_size.is_power_of_two());
+        assert!(
+            modulus % 2 == 1 && modulus < 1 << 63,
+            "the modulus must be odd and smaller than 2^63, got {modulus}",
+        );
+        let psi = psi % modulus;
+        assert_eq!(
+            pow_mod(psi, polynomial_size as u64, modulus),
+            modulus - 1,
+            "psi must be a primitive 2N-th root of unity",
+        );
+
+        let n = polynomial_size;
+        let psi_inv = pow_mod(psi, 2 * n as u64 - 1, modulus);
+
+        let powers = |root: u64| -> Vec<u64> {
+            let mut powers = Vec::with_capacity(n);
+            let mut power = 1;
+            for _ in 0..n {
+                powers.push(power);
+                power = mul_mod(power, root, modulus);
+            }
+            bit_reverse_in_place(Polynomial::new(powers.as_mut_slice(), n));
+            powers
+        };
+        let psi_rev = powers(psi);
+        let psi_inv_rev = powers(psi_inv);
+        let psi_rev_shoup = psi_rev.iter().map(|w| shoup(*w, modulus)).collect();
+        let psi_inv_rev_shoup = psi_inv_rev.iter().map(|w| shoup(*w, modulus)).collect();
+
+        // n^-1 = n^(p - 2) by Fermat's little theorem
+        let n_inv = pow_mod(n as u64 % modulus, modulus - 2, modulus);
+
+        Self {
+            modulus,
+            polynomial_size,
+            psi_rev,
+            psi_rev_shoup,
+            psi_inv_rev,
+            psi_inv_rev_shoup,
+            n_inv,
+            n_inv_shoup: shoup(n_inv, modulus),
+            reducer: BarrettReducer::new(modulus),
+        }
+    }
+
+    pub fn modulus(&self) -> u64 {
+        self.modulus
+    }
+
+    pub fn polynomial_size(&self) -> usize {
+        self.polynomial_size
+    }
+
+    /// Replaces `polynomial`, whose coefficients must be in `[0, modulus)`, with its transform.
+    pub fn forward(&self, polynomial: Polynomial<&mut [u64]>) {
+        debug_assert_eq!(polynomial.polynomial_size, self.polynomial_size);
+        let a = polynomial.into_data();
+        let p = self.modulus;
+        debug_assert!(a.iter().all(|a| *a < p));
+
+        let n = self.polynomial_size;
+        let mut t = n;
+        let mut m = 1;
+        while m < n {
+            t /= 2;
+            for i in 0..m {
+                let w = self.psi_rev[m + i];
+                let w_shoup = self.psi_rev_shoup[m + i];
+                let (lo, hi) = a[2 * i * t..2 * (i + 1) * t].split_at_mut(t);
+                for (u, v) in zip_eq(lo, hi) {
+                    let x = *u;
+                    let y = mul_mod_shoup(*v, w, w_shoup, p);
+                    *u = add_mod(x, y, p);
+                    *v = sub_mod(x, y, p);
+                }
+            }
+            m *= 2;
+        }
+    }
+
+    /// Inverse of [`Ntt::forward`].
+    pub fn inverse(&self, polynomial: Polynomial<&mut [u64]>) {
+        debug_assert_eq!(polynomial.polynomial_size, self.polynomial_size);
+        let a = polynomial.into_data();
+        let p = self.modulus;
+
+        let n = self.polynomial_size;
+        let mut t = 1;
+        let mut m = n;
+        while m > 1 {
+            let h = m / 2;
+            for i in 0..h {
+                let w = self.psi_inv_rev[h + i];
+                let w_shoup = self.psi_inv_rev_shoup[h + i];
+                let (lo, hi) = a[2 * i * t..2 * (i + 1) * t].split_at_mut(t);
+                for (u, v) in zip_eq(lo, hi) {
+                    let x = *u;
+                    let y = *v;
+                    *u = add_mod(x, y, p);
+                    *v = mul_mod_shoup(sub_mod(x, y, p), w, w_shoup, p);
+                }
+            }
+            t *= 2;
+            m = h;
+        }
+
+        for a in a {
+            *a = mul_mod_shoup(*a, self.n_inv, self.n_inv_shoup, p);
+        }
+    }
+
+    /// Multiplies `lhs` by `rhs` pointwise, both being in the transformed domain.
+    pub fn update_with_pointwise_mul(&self, lhs: Polynomial<&mut [u64]>, rhs: Polynomial<&[u64]>) {
+        for (a, b) in zip_eq(lhs.into_data(), rhs.into_data()) {
+            *a = self.reducer.mul_mod(*a, *b);
+        }
+    }
+
+    /// Sets `out` to the product of `lhs` and `rhs` in `Z_p[X]/(X^N+1)`. The coefficients of the
+    /// inputs must be in `[0, modulus)`.
+    pub fn negacyclic_mul(
+        &self,
+        out: Polynomial<&mut [u64]>,
+        lhs: Polynomial<&[u64]>,
+        rhs: Polynomial<&[u64]>,
+    ) {
+        self.negacyclic_mul_with_scratch(out, lhs, rhs, &mut Workspace::new());
+    }
+
+    /// Same as [`Ntt::negacyclic_mul`], with the transform of `rhs` stored in `workspace` instead
+    /// of a new allocation.
+    pub fn negacyclic_mul_with_scratch(
+        &self,
+        mut out: Polynomial<&mut [u64]>,
+        lhs: Polynomial<&[u64]>,
+        rhs: Polynomial<&[u64]>,
+        workspace: &mut Workspace,
+    ) {
+        let mut rhs_ntt = workspace.polynomial_mut(self.polynomial_size);
+        rhs_ntt
+            .as_mut_view()
+            .into_data()
+            .copy_from_slice(rhs.into_data());
+
+        out.as_mut_view()
+            .into_data()
+            .copy_from_slice(lhs.into_data());
+        self.forward(out.as_mut_view());
+        self.forward(rhs_ntt.as_mut_view());
+        self.update_with_pointwise_mul(out.as_mut_view(), rhs_ntt.as_view());
+        self.inverse(out);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::c_api::types::tests::random_coefficients;
+    use concrete_csprng::generators::{RandomGenerator, SoftwareRandomGenerator};
+    use concrete_csprng::seeders::Seed;
+
+    fn naive_negacyclic_mul_mod(lhs: &[u64], rhs: &[u64], p: u64) -> Vec<u64> {
+        let n = lhs.len();
+        let mut out = vec![0_u64; n];
+        for (i, lhs) in lhs.iter().enumerate() {
+            for (j, rhs) in rhs.iter().enumerate() {
+                let product = mul_mod(*lhs, *rhs, p);
+                if i + j < n {
+                    out[i + j] = add_mod(out[i + j], product, p);
+                } else {
+                    out[i + j - n] = sub_mod(out[i + j - n], product, p);
+                }
+            }
+        }
+        out
+    }
+
+    fn check_mul(ntt: &Ntt, csprng: &mut SoftwareRandomGenerator) {
+        let n = ntt.polynomial_size();
+        let p = ntt.modulus();
+        let mut random = || {
+            let mut coefficients = random_coefficients(csprng, n);
+            coefficients.iter_mut().for_each(|a| *a %= p);
+            coefficients
+        };
+        let lhs = random();
+        let rhs = random();
+
+        let mut out = vec![0_u64; n];
+        ntt.negacyclic_mul(
+            Polynomial::new(out.as_mut_slice(), n),
+            Polynomial::new(lhs.as_slice(), n),
+            Polynomial::new(rhs.as_slice(), n),
+        );
+        assert_eq!(out, naive_negacyclic_mul_mod(&lhs, &rhs, p));
+
+        // the workspace holds leftovers from a previous use
+        let mut workspace = Workspace::new();
+        workspace.polynomial_mut(n).set_to_constant(u64::MAX);
+        let mut out_with_scratch = vec![0_u64; n];
+        ntt.negacyclic_mul_with_scratch(
+            Polynomial::new(out_with_scratch.as_mut_slice(), n),
+            Polynomial::new(lhs.as_slice(), n),
+            Polynomial::new(rhs.as_slice(), n),
+            &mut workspace,
+        );
+        assert_eq!(out_with_scratch, out);
+
+        let mut round_trip = lhs.clone();
+        ntt.forward(Polynomial::new(round_trip.as_mut_slice(), n));
+        ntt.inverse(Polynomial::new(round_trip.as_mut_slice(), n));
+        assert_eq!(round_trip, lhs);
+    }
+
+    #[test]
+    fn negacyclic_mul_n8() {
+        let mut csprng = SoftwareRandomGenerator::new(Seed(0));
+        // 3 generates the multiplicative group modulo 17, so 3 is a primitive 16-th root of unity
+        check_mul(&Ntt::new(8, 17, 3), &mut csprng);
+    }
+
+    #[test]
+    fn negacyclic_mul_n1024() {
+        let mut csprng = SoftwareRandomGenerator::new(Seed(0));
+        // the largest prime below 2^62 congruent to 1 modulo 2048
+        let p = 4611686018427365377;
+        let psi = 1482597879546526807;
+        check_mul(&Ntt::new(1024, p, psi), &mut csprng);
+
+        // and a small one, with psi of order 2048 modulo 12289
+        check_mul(&Ntt::new(1024, 12289, 1945), &mut csprng);
+    }
+
+    #[test]
+    fn negacyclic_mul_small_integers_is_exact() {
+        // |sum| < 1024 * 2^10 * 2^10 < p/2 so the product over the integers is recovered
+        let n = 1024;
+        let p = 4611686018427365377;
+        let ntt = Ntt::new(n, p, 1482597879546526807);
+
+        let lhs: Vec<u64> = (0..n as u64).map(|i| (i * 37) % 1024).collect();
+        let rhs: Vec<u64> = (0..n as u64).map(|i| (i * 91 + 5) % 1024).collect();
+        let mut out = vec![0_u64; n];
+        ntt.negacyclic_mul(
+            Polynomial::new(out.as_mut_slice(), n),
+            Polynomial::new(lhs.as_slice(), n),
+            Polynomial::new(rhs.as_slice(), n),
+        );
+
+        // the negative coefficients are represented as p - |x|, map them back modulo 2^64
+        let out: Vec<u64> = out
+            .iter()
+            .map(|x| if *x > p / 2 { (*x).wrapping_sub(p) } else { *x })
+            .collect();
+
+        let mut expected = vec![0_u64; n];
+        let mut expected_polynomial = Polynomial::new(expected.as_mut_slice(), n);
+        expected_polynomial.update_with_wrapping_mul(
+            Polynomial::new(lhs.as_slice(), n),
+            Polynomial::new(rhs.as_slice(), n),
+        );
+        assert_eq!(out, expected);
+    }
+
+    #[test]
+    #[should_panic(expected = "primitive 2N-th root")]
+    fn new_checks_root() {
+        // 2 has order 8 modulo 17
+        Ntt::new(8, 17, 2);
+    }
+}
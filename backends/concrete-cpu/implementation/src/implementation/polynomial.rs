@@ -1,4 +1,17 @@
+use aligned_vec::CACHELINE_ALIGN;
+use alloc::vec::Vec;
+use concrete_fft::c64;
+use dyn_stack::{DynStack, ReborrowMut, SizeOverflow, StackReq};
+use pulp::{as_arrays, as_arrays_mut};
+
+use super::fft::FftView;
+use super::types::fourier_polynomial::FourierPolynomial;
+use super::types::fourier_polynomial_list::FourierPolynomialList;
 use super::types::polynomial::Polynomial;
+use super::types::polynomial_list::PolynomialList;
+use super::types::{PolynomialCount, PolynomialSize};
+use super::workspace::Workspace;
+use super::{as_mut_uninit, zip_eq};
 
 pub fn update_with_wrapping_unit_monomial_div(
     mut polynomial: Polynomial<&mut [u64]>,
@@ -45,6 +58,33 @@ pub fn update_with_wrapping_monic_monomial_mul(
     }
 }
 
+/// Permutes the coefficients of `polynomial`, swapping the coefficients of index `i` and `j` where
+/// `j` is `i` with its `log2(N)` bits reversed. This is the reordering between the natural order
+/// and the output order of the radix-2 transforms, such as the [`Ntt`](super::ntt::Ntt).
+///
+/// # Panics
+///
+/// Panics if the polynomial size is not a power of two.
+pub fn bit_reverse_in_place(polynomial: Polynomial<&mut [u64]>) {
+    let n = polynomial.polynomial_size;
+    assert!(
+        n.is_power_of_two(),
+        "the polynomial size must be a power of two, got {n}",
+    );
+    let log_n = n.trailing_zeros();
+    if log_n == 0 {
+        return;
+    }
+
+    let polynomial = polynomial.into_data();
+    for i in 0..n {
+        let j = i.reverse_bits() >> (usize::BITS - log_n);
+        if i < j {
+            polynomial.swap(i, j);
+        }
+    }
+}
+
 pub fn update_with_wrapping_add_mul(
     polynomial: Polynomial<&mut [u64]>,
     lhs_polynomial: Polynomial<&[u64]>,
@@ -104,3 +144,1422 @@ pub fn update_with_wrapping_sub_mul(
         }
     }
 }
+
+/// Portable version of the coefficient-wise addition, used when AVX2 is not available.
+pub fn wrapping_add_scalar(lhs: &mut [u64], rhs: &[u64]) {
+    for (a, b) in zip_eq(lhs, rhs) {
+        *a = a.wrapping_add(*b);
+    }
+}
+
+/// Portable version of the coefficient-wise subtraction, used when AVX2 is not available.
+pub fn wrapping_sub_scalar(lhs: &mut [u64], rhs: &[u64]) {
+    for (a, b) in zip_eq(lhs, rhs) {
+        *a = a.wrapping_sub(*b);
+    }
+}
+
+fn wrapping_add(lhs: &mut [u64], rhs: &[u64]) {
+    #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+    x86::wrapping_add(lhs, rhs);
+
+    #[cfg(not(any(target_arch = "x86_64", target_arch = "x86")))]
+    wrapping_add_scalar(lhs, rhs);
+}
+
+fn wrapping_sub(lhs: &mut [u64], rhs: &[u64]) {
+    #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+    x86::wrapping_sub(lhs, rhs);
+
+    #[cfg(not(any(target_arch = "x86_64", target_arch = "x86")))]
+    wrapping_sub_scalar(lhs, rhs);
+}
+
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+mod x86 {
+    use crate::implementation::convert::x86::simd_cast;
+    use crate::implementation::zip_eq;
+    use pulp::{as_arrays, as_arrays_mut, simd_type};
+
+    simd_type! {
+        pub struct Avx2 {
+            pub avx: "avx",
+            pub avx2: "avx2",
+        }
+    }
+
+    pub fn wrapping_add_avx2(simd: Avx2, lhs: &mut [u64], rhs: &[u64]) {
+        debug_assert_eq!(lhs.len(), rhs.len());
+
+        // 4×u64
+        let (lhs, lhs_tail) = as_arrays_mut::<4, _>(lhs);
+        let (rhs, rhs_tail) = as_arrays::<4, _>(rhs);
+
+        simd.vectorize(|| {
+            let simd = simd.avx2;
+            for (a, b) in zip_eq(lhs, rhs) {
+                *a = simd_cast(simd._mm256_add_epi64(simd_cast(*a), simd_cast(*b)));
+            }
+        });
+        super::wrapping_add_scalar(lhs_tail, rhs_tail);
+    }
+
+    pub fn wrapping_sub_avx2(simd: Avx2, lhs: &mut [u64], rhs: &[u64]) {
+        debug_assert_eq!(lhs.len(), rhs.len());
+
+        // 4×u64
+        let (lhs, lhs_tail) = as_arrays_mut::<4, _>(lhs);
+        let (rhs, rhs_tail) = as_arrays::<4, _>(rhs);
+
+        simd.vectorize(|| {
+            let simd = simd.avx2;
+            for (a, b) in zip_eq(lhs, rhs) {
+                *a = simd_cast(simd._mm256_sub_epi64(simd_cast(*a), simd_cast(*b)));
+            }
+        });
+        super::wrapping_sub_scalar(lhs_tail, rhs_tail);
+    }
+
+    pub fn wrapping_add(lhs: &mut [u64], rhs: &[u64]) {
+        if let Some(simd) = Avx2::try_new() {
+            return wrapping_add_avx2(simd, lhs, rhs);
+        }
+        super::wrapping_add_scalar(lhs, rhs);
+    }
+
+    pub fn wrapping_sub(lhs: &mut [u64], rhs: &[u64]) {
+        if let Some(simd) = Avx2::try_new() {
+            return wrapping_sub_avx2(simd, lhs, rhs);
+        }
+        super::wrapping_sub_scalar(lhs, rhs);
+    }
+}
+
+#[cfg(feature = "checked-arith")]
+std::thread_local! {
+    static LAST_OVERFLOWED: core::cell::Cell<bool> = const { core::cell::Cell::new(false) };
+}
+
+/// Returns `true` if the last call to [`Polynomial::update_with_wrapping_add`],
+/// [`Polynomial::update_with_wrapping_sub`] or [`Polynomial::update_with_wrapping_add_mul`]
+/// on this thread wrapped around `2^64` for at least one coefficient.
+///
+/// This is meant to help catching accumulations that exceed the intended scale when developing
+/// new algorithms. The result is still computed modulo `2^64`.
+#[cfg(feature = "checked-arith")]
+pub fn last_overflowed() -> bool {
+    LAST_OVERFLOWED.with(|flag| flag.get())
+}
+
+#[cfg(feature = "checked-arith")]
+fn record_overflow(lhs: &[u64], rhs: &[u64], overflows: impl Fn(u64, u64) -> bool) {
+    let overflowed = zip_eq(lhs, rhs).any(|(a, b)| overflows(*a, *b));
+    LAST_OVERFLOWED.with(|flag| flag.set(overflowed));
+}
+
+impl Polynomial<&mut [u64]> {
+    /// Sets every coefficient of `self` to zero.
+    pub fn fill_with_zero(&mut self) {
+        self.as_mut_view().into_data().fill(0);
+    }
+
+    /// Sets `self` to the constant polynomial `c`, i.e. the degree-0 coefficient is `c` and all
+    /// the others are zero.
+    pub fn set_to_constant(&mut self, c: u64) {
+        let polynomial = self.as_mut_view().into_data();
+        polynomial.fill(0);
+        polynomial[0] = c;
+    }
+
+    /// Swaps the coefficients of degree `i` and `N - i`, for `i` in `1..N`. The constant
+    /// coefficient is left in place.
+    pub fn reverse_in_place(&mut self) {
+        self.as_mut_view().into_data()[1..].reverse();
+    }
+
+    /// Negates every coefficient of `self`, modulo `2^64`.
+    pub fn negate_in_place(&mut self) {
+        for a in self.as_mut_view().into_data() {
+            *a = a.wrapping_neg();
+        }
+    }
+
+    /// Sets `self` to `input`, with coefficients in `[0, 2^from_log)`, rescaled to the modulus
+    /// `2^to_log`, i.e. each coefficient `x` becomes the closest integer to
+    /// `x * 2^to_log / 2^from_log`, reduced modulo `2^to_log`.
+    ///
+    /// Growing the modulus is a left shift, and is exact. Shrinking it rounds ties up, as in
+    /// [`lwe_modulus_switch`](super::bootstrap::lwe_modulus_switch), so a coefficient close
+    /// enough to `2^from_log` wraps around to zero. Equal moduli copy `input`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `from_log` or `to_log` is not in `1..=64`.
+    pub fn rescale_modulus(&mut self, input: Polynomial<&[u64]>, from_log: usize, to_log: usize) {
+        let bits = 1..=u64::BITS as usize;
+        assert!(
+            bits.contains(&from_log) && bits.contains(&to_log),
+            "the logarithms of the moduli must be in 1..=64, got {from_log} and {to_log}",
+        );
+        debug_assert_eq!(self.polynomial_size, input.polynomial_size);
+
+        let mask = u64::MAX >> (u64::BITS as usize - to_log);
+        for (out, &x) in zip_eq(self.as_mut_view().into_data(), input.into_data()) {
+            debug_assert!(from_log == u64::BITS as usize || x >> from_log == 0);
+            *out = if to_log >= from_log {
+                x << (to_log - from_log)
+            } else {
+                // keep one extra bit below the output precision, which decides the rounding
+                let shifted = x >> (from_log - to_log - 1);
+                ((shifted >> 1) + (shifted & 1)) & mask
+            };
+        }
+    }
+
+    /// Adds `other` to `self`, coefficient-wise, modulo `2^64`.
+    pub fn update_with_wrapping_add(&mut self, other: Polynomial<&[u64]>) {
+        debug_assert_eq!(self.polynomial_size, other.polynomial_size);
+        #[cfg(feature = "checked-arith")]
+        record_overflow(
+            self.as_view().into_data(),
+            other.as_view().into_data(),
+            |a, b| a.overflowing_add(b).1,
+        );
+        wrapping_add(self.as_mut_view().into_data(), other.into_data());
+    }
+
+    /// Subtracts `other` from `self`, coefficient-wise, modulo `2^64`.
+    pub fn update_with_wrapping_sub(&mut self, other: Polynomial<&[u64]>) {
+        debug_assert_eq!(self.polynomial_size, other.polynomial_size);
+        #[cfg(feature = "checked-arith")]
+        record_overflow(
+            self.as_view().into_data(),
+            other.as_view().into_data(),
+            |a, b| a.overflowing_sub(b).1,
+        );
+        wrapping_sub(self.as_mut_view().into_data(), other.into_data());
+    }
+
+    /// Adds `other * scalar` to `self`, coefficient-wise, modulo `2^64`.
+    pub fn update_with_wrapping_add_mul(&mut self, other: Polynomial<&[u64]>, scalar: u64) {
+        debug_assert_eq!(self.polynomial_size, other.polynomial_size);
+        #[cfg(feature = "checked-arith")]
+        record_overflow(
+            self.as_view().into_data(),
+            other.as_view().into_data(),
+            |a, b| {
+                b.checked_mul(scalar)
+                    .and_then(|product| a.checked_add(product))
+                    .is_none()
+            },
+        );
+        for (a, b) in zip_eq(self.as_mut_view().into_data(), other.into_data()) {
+            *a = a.wrapping_add(b.wrapping_mul(scalar));
+        }
+    }
+
+    /// Sets `self` to `input * X^monomial_degree` in the negacyclic ring `Z_q[X]/(X^N+1)`.
+    ///
+    /// Since `X^(2N) = 1`, the degree is first reduced modulo `2N`.
+    pub fn update_with_wrapping_monic_monomial_mul(
+        &mut self,
+        input: Polynomial<&[u64]>,
+        monomial_degree: usize,
+    ) {
+        debug_assert_eq!(self.polynomial_size, input.polynomial_size);
+        let monomial_degree = monomial_degree % (2 * self.polynomial_size);
+
+        self.as_mut_view()
+            .into_data()
+            .copy_from_slice(input.into_data());
+        update_with_wrapping_monic_monomial_mul(self.as_mut_view(), monomial_degree);
+    }
+
+    /// Sets `self` to `input * X^-monomial_degree` in the negacyclic ring `Z_q[X]/(X^N+1)`, which
+    /// is the inverse of [`Polynomial::update_with_wrapping_monic_monomial_mul`].
+    ///
+    /// Since `X^-k = -X^(N-k)`, the coefficients are shifted down by `k`, and the ones that wrap
+    /// around to the top are negated. The degree is first reduced modulo `2N`.
+    pub fn update_with_wrapping_monic_monomial_div(
+        &mut self,
+        input: Polynomial<&[u64]>,
+        monomial_degree: usize,
+    ) {
+        debug_assert_eq!(self.polynomial_size, input.polynomial_size);
+        let monomial_degree = monomial_degree % (2 * self.polynomial_size);
+
+        self.as_mut_view()
+            .into_data()
+            .copy_from_slice(input.into_data());
+        update_with_wrapping_unit_monomial_div(self.as_mut_view(), monomial_degree);
+    }
+
+    /// Sets `self` to `input(X^k)` in the negacyclic ring `Z_q[X]/(X^N+1)`.
+    ///
+    /// The coefficient of degree `i` of `input` is moved to degree `i * k mod 2N`, and negated if
+    /// that degree is `>= N`, since `X^N = -1`. With `N` a power of two, this is an automorphism of
+    /// the ring for every odd `k`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `k` is even.
+    pub fn apply_automorphism(&mut self, input: Polynomial<&[u64]>, k: usize) {
+        assert!(k % 2 == 1, "the automorphism index must be odd, got {k}");
+        debug_assert_eq!(self.polynomial_size, input.polynomial_size);
+
+        let n = self.polynomial_size;
+        let k = k % (2 * n);
+        let out = self.as_mut_view().into_data();
+        for (i, a) in input.iter().enumerate() {
+            let degree = i * k % (2 * n);
+            if degree < n {
+                out[degree] = *a;
+            } else {
+                out[degree - n] = a.wrapping_neg();
+            }
+        }
+    }
+
+    /// Sets `self` to the product of `lhs` and `rhs` in the negacyclic ring `Z_q[X]/(X^N+1)`.
+    ///
+    /// The terms of degree `>= N` wrap around with a sign flip, since `X^N = -1`.
+    pub fn update_with_wrapping_mul(&mut self, lhs: Polynomial<&[u64]>, rhs: Polynomial<&[u64]>) {
+        debug_assert_eq!(self.polynomial_size, lhs.polynomial_size);
+        debug_assert_eq!(self.polynomial_size, rhs.polynomial_size);
+
+        self.fill_with_zero();
+        wrapping_add_negacyclic_mul(self.as_mut_view(), lhs, rhs);
+    }
+}
+
+// Adds the negacyclic product of `lhs` and `rhs` to `out`, with the schoolbook algorithm.
+fn wrapping_add_negacyclic_mul(
+    out: Polynomial<&mut [u64]>,
+    lhs: Polynomial<&[u64]>,
+    rhs: Polynomial<&[u64]>,
+) {
+    let polynomial = out.into_data();
+    let lhs = lhs.into_data();
+    let rhs = rhs.into_data();
+
+    let dim = polynomial.len();
+    for (i, lhs) in lhs.iter().enumerate() {
+        for (j, rhs) in rhs.iter().enumerate() {
+            let target_degree = i + j;
+            let product = lhs.wrapping_mul(*rhs);
+
+            if target_degree < dim {
+                polynomial[target_degree] = polynomial[target_degree].wrapping_add(product);
+            } else {
+                polynomial[target_degree - dim] =
+                    polynomial[target_degree - dim].wrapping_sub(product);
+            }
+        }
+    }
+}
+
+/// Operator form of [`Polynomial::update_with_wrapping_add`].
+///
+/// # Panics
+///
+/// Panics if the polynomials do not have the same size.
+impl core::ops::AddAssign<Polynomial<&[u64]>> for Polynomial<&mut [u64]> {
+    fn add_assign(&mut self, rhs: Polynomial<&[u64]>) {
+        assert_eq!(self.polynomial_size, rhs.polynomial_size);
+        self.update_with_wrapping_add(rhs);
+    }
+}
+
+/// Operator form of [`Polynomial::update_with_wrapping_sub`].
+///
+/// # Panics
+///
+/// Panics if the polynomials do not have the same size.
+impl core::ops::SubAssign<Polynomial<&[u64]>> for Polynomial<&mut [u64]> {
+    fn sub_assign(&mut self, rhs: Polynomial<&[u64]>) {
+        assert_eq!(self.polynomial_size, rhs.polynomial_size);
+        self.update_with_wrapping_sub(rhs);
+    }
+}
+
+impl core::ops::AddAssign<Polynomial<&[u64]>> for Polynomial<Vec<u64>> {
+    fn add_assign(&mut self, rhs: Polynomial<&[u64]>) {
+        let mut view = self.as_mut_view();
+        view += rhs;
+    }
+}
+
+impl core::ops::SubAssign<Polynomial<&[u64]>> for Polynomial<Vec<u64>> {
+    fn sub_assign(&mut self, rhs: Polynomial<&[u64]>) {
+        let mut view = self.as_mut_view();
+        view -= rhs;
+    }
+}
+
+// Below this size, `update_with_wrapping_karatsuba_mul` falls back to the schoolbook algorithm.
+// Not `pub`, so that cbindgen does not export it to the C header.
+const KARATSUBA_CUTOFF: usize = 32;
+
+impl Polynomial<&mut [u64]> {
+    /// Sets `self` to the product of `lhs` and `rhs` in the negacyclic ring `Z_q[X]/(X^N+1)`,
+    /// using Karatsuba multiplication.
+    ///
+    /// The full product of degree `2N - 2` is computed recursively, splitting each operand in two
+    /// halves (the upper one being one coefficient longer when the size is odd), down to
+    /// 32 coefficients, where the schoolbook algorithm is used. It is then folded modulo
+    /// `X^N + 1`. The result is identical to [`Polynomial::update_with_wrapping_mul`].
+    pub fn update_with_wrapping_karatsuba_mul(
+        &mut self,
+        lhs: Polynomial<&[u64]>,
+        rhs: Polynomial<&[u64]>,
+    ) {
+        debug_assert_eq!(self.polynomial_size, lhs.polynomial_size);
+        debug_assert_eq!(self.polynomial_size, rhs.polynomial_size);
+
+        self.update_with_wrapping_karatsuba_mul_with_scratch(lhs, rhs, &mut Workspace::new());
+    }
+
+    /// Same as [`Polynomial::update_with_wrapping_karatsuba_mul`], with the temporary buffers
+    /// taken from `workspace` instead of being allocated.
+    pub fn update_with_wrapping_karatsuba_mul_with_scratch(
+        &mut self,
+        lhs: Polynomial<&[u64]>,
+        rhs: Polynomial<&[u64]>,
+        workspace: &mut Workspace,
+    ) {
+        debug_assert_eq!(self.polynomial_size, lhs.polynomial_size);
+        debug_assert_eq!(self.polynomial_size, rhs.polynomial_size);
+
+        let n = self.polynomial_size;
+        let buffer = workspace.buffer(2 * n + karatsuba_scratch_len(n));
+        let (full, scratch) = buffer.split_at_mut(2 * n);
+        karatsuba_mul(full, lhs.into_data(), rhs.into_data(), scratch);
+
+        let (low, high) = full.split_at(n);
+        for (out, (low, high)) in zip_eq(self.as_mut_view().into_data(), zip_eq(low, high)) {
+            *out = low.wrapping_sub(*high);
+        }
+    }
+}
+
+fn karatsuba_scratch_len(n: usize) -> usize {
+    if n <= KARATSUBA_CUTOFF {
+        0
+    } else {
+        let high = n - n / 2;
+        4 * high + karatsuba_scratch_len(high)
+    }
+}
+
+// Writes the full (non-reduced) product of `lhs` and `rhs` in `out[..2n - 1]`, and zero in
+// `out[2n - 1]`.
+fn karatsuba_mul(out: &mut [u64], lhs: &[u64], rhs: &[u64], scratch: &mut [u64]) {
+    let n = lhs.len();
+    debug_assert_eq!(n, rhs.len());
+    debug_assert_eq!(out.len(), 2 * n);
+
+    if n <= KARATSUBA_CUTOFF {
+        out.fill(0);
+        for (i, lhs) in lhs.iter().enumerate() {
+            for (j, rhs) in rhs.iter().enumerate() {
+                out[i + j] = out[i + j].wrapping_add(lhs.wrapping_mul(*rhs));
+            }
+        }
+        return;
+    }
+
+    // lhs = lhs_0 + X^m lhs_1, and the same for rhs, with deg(lhs_0) < m <= deg(lhs_1) + 1
+    let m = n / 2;
+    let h = n - m;
+    let (lhs_0, lhs_1) = lhs.split_at(m);
+    let (rhs_0, rhs_1) = rhs.split_at(m);
+
+    // z_0 = lhs_0 * rhs_0 and z_2 = lhs_1 * rhs_1 go directly to their place in the output
+    {
+        let (z_0, z_2) = out.split_at_mut(2 * m);
+        karatsuba_mul(z_0, lhs_0, rhs_0, scratch);
+        karatsuba_mul(z_2, lhs_1, rhs_1, scratch);
+    }
+
+    // z_1 = (lhs_0 + lhs_1) * (rhs_0 + rhs_1) - z_0 - z_2
+    let (lhs_sum, scratch) = scratch.split_at_mut(h);
+    let (rhs_sum, scratch) = scratch.split_at_mut(h);
+    let (z_1, scratch) = scratch.split_at_mut(2 * h);
+    lhs_sum.copy_from_slice(lhs_1);
+    rhs_sum.copy_from_slice(rhs_1);
+    wrapping_add_scalar(&mut lhs_sum[..m], lhs_0);
+    wrapping_add_scalar(&mut rhs_sum[..m], rhs_0);
+    karatsuba_mul(z_1, lhs_sum, rhs_sum, scratch);
+
+    wrapping_sub_scalar(&mut z_1[..2 * m], &out[..2 * m]);
+    wrapping_sub_scalar(z_1, &out[2 * m..]);
+
+    wrapping_add_scalar(&mut out[m..m + 2 * h], z_1);
+}
+
+impl Polynomial<&[u64]> {
+    /// Evaluates the polynomial at `x`, modulo `2^64`, with Horner's method.
+    ///
+    /// This is the evaluation of the polynomial seen as an element of `Z_q[X]`, not of the
+    /// negacyclic ring `Z_q[X]/(X^N+1)`. An empty polynomial evaluates to zero, and the evaluation
+    /// at zero is the constant coefficient.
+    pub fn wrapping_eval(&self, x: u64) -> u64 {
+        self.as_view()
+            .iter()
+            .rev()
+            .fold(0, |acc, a| acc.wrapping_mul(x).wrapping_add(*a))
+    }
+}
+
+/// Statistics of the coefficients of a polynomial, see [`coefficient_stats`].
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct CoefficientStats {
+    pub min: i64,
+    pub max: i64,
+    pub mean: f64,
+    /// Population variance, i.e. the mean of the squared deviations from `mean`.
+    pub variance: f64,
+}
+
+/// Returns the minimum, maximum, mean and variance of the coefficients of `polynomial`, e.g. the
+/// noise left in a decrypted plaintext once the message is subtracted.
+///
+/// Each coefficient is read as a signed torus value centered around zero, i.e. as an `i64`, so
+/// that `2^64 - 1` is `-1`. The values `2^63` and above are negative, so a noise close to half of
+/// the torus does not measure anything meaningful.
+///
+/// # Panics
+///
+/// Panics if the polynomial is empty.
+pub fn coefficient_stats(polynomial: Polynomial<&[u64]>) -> CoefficientStats {
+    let coefficients = polynomial.into_data();
+    assert!(!coefficients.is_empty(), "the polynomial must not be empty");
+
+    let signed = || coefficients.iter().map(|&a| a as i64);
+    let count = coefficients.len() as f64;
+
+    // the sum is exact, so the mean is only rounded once
+    let mean = signed().map(i128::from).sum::<i128>() as f64 / count;
+    let variance = signed()
+        .map(|a| {
+            let deviation = a as f64 - mean;
+            deviation * deviation
+        })
+        .sum::<f64>()
+        / count;
+
+    CoefficientStats {
+        min: signed().min().unwrap(),
+        max: signed().max().unwrap(),
+        mean,
+        variance,
+    }
+}
+
+impl PolynomialList<&[u64]> {
+    /// Adds `sum_i self_i * other_i` to `out`, where the products are taken in the negacyclic ring
+    /// `Z_q[X]/(X^N+1)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the two lists do not have the same count, or if `self`, `other` and `out` do not
+    /// all have the same polynomial size.
+    pub fn wrapping_dot_product(
+        &self,
+        other: PolynomialList<&[u64]>,
+        out: &mut Polynomial<&mut [u64]>,
+    ) {
+        assert_eq!(self.count, other.count);
+        assert_eq!(self.polynomial_size, other.polynomial_size);
+        assert_eq!(self.polynomial_size, out.polynomial_size);
+
+        for (lhs, rhs) in zip_eq(self.iter_polynomial(), other.iter_polynomial()) {
+            wrapping_add_negacyclic_mul(out.as_mut_view(), lhs, rhs);
+        }
+    }
+
+    /// Returns the `self.count * other.count` negacyclic products `self_i * other_j`, in
+    /// row-major order, i.e. the product `self_i * other_j` is the polynomial of index
+    /// `i * other.count + j`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the two lists do not have the same polynomial size.
+    pub fn tensor_product(&self, other: PolynomialList<&[u64]>) -> PolynomialList<Vec<u64>> {
+        assert_eq!(self.polynomial_size, other.polynomial_size);
+
+        let mut out = PolynomialList::new_zeroed(
+            PolynomialSize(self.polynomial_size),
+            PolynomialCount(self.count * other.count),
+        );
+        let mut out_polynomials = out.iter_polynomial_mut();
+        for lhs in self.iter_polynomial() {
+            for (out, rhs) in zip_eq(
+                out_polynomials.by_ref().take(other.count),
+                other.iter_polynomial(),
+            ) {
+                wrapping_add_negacyclic_mul(out, lhs.as_view(), rhs);
+            }
+        }
+        drop(out_polynomials);
+        out
+    }
+}
+
+/// Returns the required memory for [`Polynomial::update_with_fft_mul`].
+pub fn fft_mul_scratch(fft: FftView<'_>) -> Result<StackReq, SizeOverflow> {
+    let fourier_scratch = StackReq::try_new_aligned::<f64>(fft.polynomial_size(), CACHELINE_ALIGN)?;
+    StackReq::try_all_of([
+        fourier_scratch,
+        fourier_scratch,
+        fourier_scratch,
+        StackReq::try_any_of([fft.forward_scratch()?, fft.backward_scratch()?])?,
+    ])
+}
+
+impl Polynomial<&mut [u64]> {
+    /// Sets `self` to the product of `lhs` and `rhs` in the negacyclic ring `Z_q[X]/(X^N+1)`,
+    /// computed with a negacyclic FFT.
+    ///
+    /// `lhs` is viewed as a polynomial with small signed integer coefficients, and `rhs` as a
+    /// polynomial over the torus. The result is exact up to the floating point error of the
+    /// transform, which grows with the magnitude of the coefficients of `lhs`.
+    pub fn update_with_fft_mul(
+        &mut self,
+        lhs: Polynomial<&[u64]>,
+        rhs: Polynomial<&[u64]>,
+        fft: FftView<'_>,
+        stack: DynStack<'_>,
+    ) {
+        let polynomial_size = self.polynomial_size;
+        debug_assert_eq!(polynomial_size, fft.polynomial_size());
+
+        let (mut lhs_fourier, stack) =
+            stack.make_aligned_with(polynomial_size, CACHELINE_ALIGN, |_| 0.0);
+        let (mut rhs_fourier, stack) =
+            stack.make_aligned_with(polynomial_size, CACHELINE_ALIGN, |_| 0.0);
+        let (mut product_fourier, mut stack) =
+            stack.make_aligned_with(polynomial_size, CACHELINE_ALIGN, |_| 0.0);
+
+        let mut lhs_fourier = FourierPolynomial::new(&mut *lhs_fourier, polynomial_size);
+        let mut rhs_fourier = FourierPolynomial::new(&mut *rhs_fourier, polynomial_size);
+        let mut product_fourier = FourierPolynomial::new(&mut *product_fourier, polynomial_size);
+
+        lhs_fourier.forward_negacyclic_as_integer(lhs, fft, stack.rb_mut());
+        rhs_fourier.forward_negacyclic_as_torus(rhs, fft, stack.rb_mut());
+        product_fourier.update_with_mul(lhs_fourier.as_view(), rhs_fourier.as_view());
+        product_fourier
+            .as_view()
+            .backward_negacyclic_as_torus(self.as_mut_view(), fft, stack);
+    }
+
+    /// Same as [`Polynomial::update_with_fft_mul`], with the scratch memory taken from
+    /// `workspace`.
+    pub fn update_with_fft_mul_with_scratch(
+        &mut self,
+        lhs: Polynomial<&[u64]>,
+        rhs: Polynomial<&[u64]>,
+        fft: FftView<'_>,
+        workspace: &mut Workspace,
+    ) {
+        self.update_with_fft_mul(
+            lhs,
+            rhs,
+            fft,
+            workspace.stack(fft_mul_scratch(fft).unwrap()),
+        );
+    }
+}
+
+impl FourierPolynomial<&mut [f64]> {
+    /// Fills `self` with the negacyclic FFT of `standard`, viewed as integers.
+    pub fn forward_negacyclic_as_integer(
+        &mut self,
+        standard: Polynomial<&[u64]>,
+        fft: FftView<'_>,
+        stack: DynStack<'_>,
+    ) {
+        debug_assert_eq!(self.polynomial_size, standard.polynomial_size);
+        fft.forward_as_integer(
+            unsafe { as_mut_uninit(self.as_mut_view().into_data()) },
+            standard.into_data(),
+            stack,
+        );
+    }
+
+    /// Fills `self` with the negacyclic FFT of `standard`, viewed as torus elements.
+    pub fn forward_negacyclic_as_torus(
+        &mut self,
+        standard: Polynomial<&[u64]>,
+        fft: FftView<'_>,
+        stack: DynStack<'_>,
+    ) {
+        debug_assert_eq!(self.polynomial_size, standard.polynomial_size);
+        fft.forward_as_torus(
+            unsafe { as_mut_uninit(self.as_mut_view().into_data()) },
+            standard.into_data(),
+            stack,
+        );
+    }
+
+    /// Sets `self` to the pointwise product of `lhs` and `rhs`.
+    ///
+    /// Transforming the result back to the standard domain yields the negacyclic product of the
+    /// polynomials that `lhs` and `rhs` were computed from.
+    pub fn update_with_mul(
+        &mut self,
+        lhs: FourierPolynomial<&[f64]>,
+        rhs: FourierPolynomial<&[f64]>,
+    ) {
+        debug_assert_eq!(self.polynomial_size, lhs.polynomial_size);
+        debug_assert_eq!(self.polynomial_size, rhs.polynomial_size);
+
+        let (out, _) = as_arrays_mut::<2, _>(self.as_mut_view().into_data());
+        let (lhs, _) = as_arrays::<2, _>(lhs.into_data());
+        let (rhs, _) = as_arrays::<2, _>(rhs.into_data());
+
+        for (out, lhs, rhs) in izip!(out, lhs, rhs) {
+            let result = c64::new(lhs[0], lhs[1]) * c64::new(rhs[0], rhs[1]);
+            *out = [result.re, result.im];
+        }
+    }
+}
+
+impl FourierPolynomial<&[f64]> {
+    /// Fills `standard` with the inverse negacyclic FFT of `self`, viewed as torus elements.
+    pub fn backward_negacyclic_as_torus(
+        &self,
+        standard: Polynomial<&mut [u64]>,
+        fft: FftView<'_>,
+        stack: DynStack<'_>,
+    ) {
+        debug_assert_eq!(self.polynomial_size, standard.polynomial_size);
+        fft.backward_as_torus(
+            unsafe { as_mut_uninit(standard.into_data()) },
+            self.data,
+            stack,
+        );
+    }
+}
+
+/// Returns the required memory for [`batch_forward_negacyclic`].
+pub fn batch_forward_negacyclic_scratch(fft: FftView<'_>) -> Result<StackReq, SizeOverflow> {
+    fft.forward_scratch()
+}
+
+/// Fills `output` with the negacyclic FFT of every polynomial of `input`, viewed as torus
+/// elements.
+///
+/// All the transforms share the plan and twiddle factors of `fft`, and the scratch memory from
+/// `stack`.
+pub fn batch_forward_negacyclic(
+    input: PolynomialList<&[u64]>,
+    mut output: FourierPolynomialList<&mut [f64]>,
+    fft: FftView<'_>,
+    mut stack: DynStack<'_>,
+) {
+    debug_assert_eq!(input.count, output.count);
+    debug_assert_eq!(input.polynomial_size, output.polynomial_size);
+
+    for (mut fourier, standard) in zip_eq(output.iter_polynomial_mut(), input.iter_polynomial()) {
+        fourier.forward_negacyclic_as_torus(standard, fft, stack.rb_mut());
+    }
+}
+
+/// Parallel version of [`batch_forward_negacyclic`], where each rayon task allocates its own
+/// scratch memory.
+#[cfg(feature = "parallel")]
+pub fn batch_forward_negacyclic_par(
+    input: PolynomialList<&[u64]>,
+    output: FourierPolynomialList<&mut [f64]>,
+    fft: FftView<'_>,
+) {
+    use dyn_stack::GlobalMemBuffer;
+    use rayon::prelude::*;
+
+    debug_assert_eq!(input.count, output.count);
+    debug_assert_eq!(input.polynomial_size, output.polynomial_size);
+
+    let polynomial_size = input.polynomial_size;
+    let scratch = batch_forward_negacyclic_scratch(fft).unwrap();
+
+    output
+        .into_data()
+        .par_chunks_exact_mut(FourierPolynomial::<&[f64]>::data_len(polynomial_size))
+        .zip_eq(input.par_iter_polynomial())
+        .for_each_init(
+            || GlobalMemBuffer::new(scratch),
+            |mem, (fourier, standard)| {
+                FourierPolynomial::new(fourier, polynomial_size).forward_negacyclic_as_torus(
+                    standard,
+                    fft,
+                    DynStack::new(mem),
+                );
+            },
+        );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::c_api::types::tests::random_coefficients;
+    use crate::implementation::fft::Fft;
+    use concrete_csprng::generators::{RandomGenerator, SoftwareRandomGenerator};
+    use concrete_csprng::seeders::Seed;
+    use dyn_stack::GlobalMemBuffer;
+
+    #[test]
+    fn rescale_modulus_shrink_rounds_half_up() {
+        // from 2^10 to 2^4, i.e. a step of 64 with ties at 32
+        let input = [0, 31, 32, 33, 64 + 31, 64 + 32, 1023 - 32, 1023 - 31, 1023];
+        let mut out = [u64::MAX; 9];
+        Polynomial::new(out.as_mut_slice(), 9).rescale_modulus(Polynomial::new(&input, 9), 10, 4);
+        assert_eq!(out, [0, 0, 1, 1, 1, 2, 15, 0, 0]);
+
+        let input = [u64::MAX, 1 << 63, (1 << 63) - 1];
+        let mut out = [0; 3];
+        Polynomial::new(out.as_mut_slice(), 3).rescale_modulus(Polynomial::new(&input, 3), 64, 1);
+        assert_eq!(out, [0, 1, 1]);
+    }
+
+    #[test]
+    fn rescale_modulus_grow_and_copy() {
+        let input = [0, 1, 5, 15];
+        let mut out = [0; 4];
+        let mut polynomial = Polynomial::new(out.as_mut_slice(), 4);
+
+        polynomial.rescale_modulus(Polynomial::new(&input, 4), 4, 10);
+        assert_eq!(polynomial.as_view().into_data(), [0, 64, 320, 960]);
+        polynomial.rescale_modulus(Polynomial::new(&input, 4), 4, 64);
+        assert_eq!(
+            polynomial.as_view().into_data(),
+            [0, 1 << 60, 5 << 60, 15 << 60]
+        );
+        polynomial.rescale_modulus(Polynomial::new(&input, 4), 4, 4);
+        assert_eq!(out, input);
+    }
+
+    #[test]
+    fn coefficient_stats_of_centered_values() {
+        // -3..=3 around zero, wrapped to the top of the torus below zero
+        let data: Vec<u64> = (-3_i64..=3).map(|a| a as u64).collect();
+        let stats = coefficient_stats(Polynomial::new(&data, 7));
+        assert_eq!(stats.min, -3);
+        assert_eq!(stats.max, 3);
+        assert_eq!(stats.mean, 0.0);
+        assert_eq!(stats.variance, 4.0);
+
+        // the extremes of the signed interpretation
+        let data = [1_u64 << 63, (1 << 63) - 1];
+        let stats = coefficient_stats(Polynomial::new(&data, 2));
+        assert_eq!((stats.min, stats.max), (i64::MIN, i64::MAX));
+        assert_eq!(stats.mean, -0.5);
+    }
+
+    #[test]
+    fn coefficient_stats_of_gaussian_noise() {
+        use crate::c_api::types::tests::to_generic;
+        use crate::implementation::encrypt::fill_with_random_gaussian;
+
+        let mut csprng = SoftwareRandomGenerator::new(Seed(0));
+        let polynomial_size = 8192;
+        // variance on the torus, i.e. a standard deviation of 2^44 on the coefficients
+        let variance = 2.0_f64.powi(-40);
+
+        let mut data = vec![0_u64; polynomial_size];
+        fill_with_random_gaussian(&mut data, variance, to_generic(&mut csprng));
+
+        let stats = coefficient_stats(Polynomial::new(&data, polynomial_size));
+        let expected_variance = variance * 2.0_f64.powi(128);
+        // the standard deviation of the empirical variance is sqrt(2 / N) ~ 1.6% of the variance
+        assert!((stats.variance / expected_variance - 1.0).abs() < 0.08);
+        assert!(
+            stats.mean.abs() < 4.0 * expected_variance.sqrt() / (polynomial_size as f64).sqrt()
+        );
+        assert!(stats.min < 0 && stats.max > 0);
+    }
+
+    #[cfg(feature = "checked-arith")]
+    #[test]
+    fn checked_arith_records_overflow() {
+        let mut data = vec![u64::MAX - 1, 0, 5];
+        let mut polynomial = Polynomial::new(data.as_mut_slice(), 3);
+
+        polynomial.update_with_wrapping_add(Polynomial::new(&[1, 2, 3], 3));
+        assert!(!last_overflowed());
+        polynomial.update_with_wrapping_add(Polynomial::new(&[1, 0, 0], 3));
+        assert!(last_overflowed());
+        assert_eq!(polynomial.as_view().into_data(), [0, 2, 8]);
+
+        polynomial.update_with_wrapping_sub(Polynomial::new(&[0, 2, 8], 3));
+        assert!(!last_overflowed());
+        polynomial.update_with_wrapping_sub(Polynomial::new(&[0, 0, 1], 3));
+        assert!(last_overflowed());
+
+        polynomial.update_with_wrapping_add_mul(Polynomial::new(&[1, 0, 0], 3), 3);
+        assert!(!last_overflowed());
+        polynomial.update_with_wrapping_add_mul(Polynomial::new(&[1, 0, 0], 3), u64::MAX);
+        assert!(last_overflowed());
+    }
+
+    #[test]
+    fn assign_operators_match_methods() {
+        let mut csprng = SoftwareRandomGenerator::new(Seed(0));
+        let polynomial_size = 64;
+        let lhs = random_coefficients(&mut csprng, polynomial_size);
+        let rhs = random_coefficients(&mut csprng, polynomial_size);
+        let rhs = Polynomial::new(rhs.as_slice(), polynomial_size);
+
+        let mut expected_sum = lhs.clone();
+        Polynomial::new(expected_sum.as_mut_slice(), polynomial_size)
+            .update_with_wrapping_add(rhs.as_view());
+        let mut expected_difference = lhs.clone();
+        Polynomial::new(expected_difference.as_mut_slice(), polynomial_size)
+            .update_with_wrapping_sub(rhs.as_view());
+
+        let mut sum = lhs.clone();
+        let mut view = Polynomial::new(sum.as_mut_slice(), polynomial_size);
+        view += rhs.as_view();
+        assert_eq!(sum, expected_sum);
+
+        let mut difference = lhs.clone();
+        let mut view = Polynomial::new(difference.as_mut_slice(), polynomial_size);
+        view -= rhs.as_view();
+        assert_eq!(difference, expected_difference);
+
+        let mut owned = Polynomial::new(lhs, polynomial_size);
+        owned += rhs.as_view();
+        assert_eq!(owned.as_view().into_data(), expected_sum);
+        owned -= rhs.as_view();
+        owned -= rhs.as_view();
+        assert_eq!(owned.into_data(), expected_difference);
+    }
+
+    #[test]
+    fn assign_operators_on_small_polynomial() {
+        let mut acc = Polynomial::new(vec![1_u64, 2, 3, 4], 4);
+        acc += Polynomial::new(&[1_u64, 1, 1, 1][..], 4);
+        acc -= Polynomial::new(&[0_u64, 0, 0, 5][..], 4);
+        assert_eq!(acc.into_data(), [2, 3, 4, 0]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn assign_operator_size_mismatch() {
+        let mut lhs = Polynomial::new(vec![0_u64; 4], 4);
+        lhs += Polynomial::new(&[0_u64; 8][..], 8);
+    }
+
+    // Computes the full product of degree `2N - 2`, then reduces it modulo `X^N + 1`.
+    fn naive_negacyclic_mul(lhs: &[u64], rhs: &[u64]) -> Vec<u64> {
+        let n = lhs.len();
+        let mut full = vec![0_u64; 2 * n];
+        for (i, lhs) in lhs.iter().enumerate() {
+            for (j, rhs) in rhs.iter().enumerate() {
+                full[i + j] = full[i + j].wrapping_add(lhs.wrapping_mul(*rhs));
+            }
+        }
+        (0..n).map(|k| full[k].wrapping_sub(full[k + n])).collect()
+    }
+
+    #[test]
+    fn fill_with_zero_and_set_to_constant() {
+        for polynomial_size in [1, 4] {
+            let mut data = vec![7_u64; polynomial_size];
+
+            Polynomial::new(data.as_mut_slice(), polynomial_size).set_to_constant(3);
+            assert_eq!(data[0], 3);
+            assert!(data[1..].iter().all(|&c| c == 0));
+
+            Polynomial::new(data.as_mut_slice(), polynomial_size).fill_with_zero();
+            assert!(data.iter().all(|&c| c == 0));
+        }
+
+        let mut data = vec![7_u64; 6];
+        PolynomialList::new(data.as_mut_slice(), PolynomialSize(2), PolynomialCount(3))
+            .fill_with_zero();
+        assert_eq!(data, [0; 6]);
+    }
+
+    #[test]
+    fn reverse_and_negate_in_place() {
+        let mut data = vec![10_u64, 11, 12, 13];
+        Polynomial::new(data.as_mut_slice(), 4).reverse_in_place();
+        assert_eq!(data, [10, 13, 12, 11]);
+
+        let mut data = vec![10_u64];
+        Polynomial::new(data.as_mut_slice(), 1).reverse_in_place();
+        assert_eq!(data, [10]);
+
+        let mut data = vec![0_u64, 1, u64::MAX, 1 << 63];
+        Polynomial::new(data.as_mut_slice(), 4).negate_in_place();
+        assert_eq!(data, [0, u64::MAX, 1, 1 << 63]);
+    }
+
+    #[test]
+    fn wrapping_add_sub_wrap_around() {
+        let mut data = vec![u64::MAX, 1, 0];
+        let other = [1, u64::MAX, 0];
+        let mut polynomial = Polynomial::new(data.as_mut_slice(), 3);
+
+        polynomial.update_with_wrapping_add(Polynomial::new(&other, 3));
+        assert_eq!(data, [0, 0, 0]);
+
+        let mut polynomial = Polynomial::new(data.as_mut_slice(), 3);
+        polynomial.update_with_wrapping_sub(Polynomial::new(&other, 3));
+        assert_eq!(data, [u64::MAX, 1, 0]);
+
+        let mut polynomial = Polynomial::new(data.as_mut_slice(), 3);
+        polynomial.update_with_wrapping_add_mul(Polynomial::new(&[2, 3, 4], 3), 1 << 63);
+        assert_eq!(data, [u64::MAX, 1 + (1 << 63), 0]);
+    }
+
+    fn naive_monic_monomial_mul(input: &[u64], monomial_degree: usize) -> Vec<u64> {
+        let n = input.len();
+        let mut out = vec![0_u64; n];
+        for (i, a) in input.iter().enumerate() {
+            let target_degree = (i + monomial_degree) % (2 * n);
+            if target_degree < n {
+                out[target_degree] = *a;
+            } else {
+                out[target_degree - n] = a.wrapping_neg();
+            }
+        }
+        out
+    }
+
+    #[test]
+    fn monic_monomial_mul_matches_naive() {
+        let mut csprng = SoftwareRandomGenerator::new(Seed(0));
+        let polynomial_size = 512;
+        let input = random_coefficients(&mut csprng, polynomial_size);
+        let mut out = vec![0_u64; polynomial_size];
+
+        let mut check = |monomial_degree: usize| {
+            Polynomial::new(out.as_mut_slice(), polynomial_size)
+                .update_with_wrapping_monic_monomial_mul(
+                    Polynomial::new(input.as_slice(), polynomial_size),
+                    monomial_degree,
+                );
+            assert_eq!(out, naive_monic_monomial_mul(&input, monomial_degree));
+            out.clone()
+        };
+
+        assert_eq!(check(0), input);
+        let negated: Vec<u64> = input.iter().map(|a| a.wrapping_neg()).collect();
+        assert_eq!(check(polynomial_size), negated);
+        assert_eq!(check(2 * polynomial_size), input);
+
+        for _ in 0..10 {
+            let monomial_degree =
+                usize::from_le_bytes(core::array::from_fn(|_| csprng.next().unwrap()))
+                    % (8 * polynomial_size);
+            check(monomial_degree);
+        }
+    }
+
+    #[test]
+    fn monic_monomial_div_inverts_mul() {
+        let mut csprng = SoftwareRandomGenerator::new(Seed(0));
+        let polynomial_size = 256;
+        let input = random_coefficients(&mut csprng, polynomial_size);
+        let mut product = vec![0_u64; polynomial_size];
+        let mut quotient = vec![0_u64; polynomial_size];
+
+        let mut div = |polynomial: &[u64], monomial_degree: usize| {
+            Polynomial::new(quotient.as_mut_slice(), polynomial_size)
+                .update_with_wrapping_monic_monomial_div(
+                    Polynomial::new(polynomial, polynomial_size),
+                    monomial_degree,
+                );
+            quotient.clone()
+        };
+
+        assert_eq!(div(&input, 0), input);
+        let negated: Vec<u64> = input.iter().map(|a| a.wrapping_neg()).collect();
+        assert_eq!(div(&input, polynomial_size), negated);
+        // X^-1 moves the constant coefficient to the top, negated
+        let shifted = div(&input, 1);
+        assert_eq!(shifted[..polynomial_size - 1], input[1..]);
+        assert_eq!(shifted[polynomial_size - 1], input[0].wrapping_neg());
+
+        for monomial_degree in [1, 3, 100, 255, 256, 257, 511, 512, 1000] {
+            Polynomial::new(product.as_mut_slice(), polynomial_size)
+                .update_with_wrapping_monic_monomial_mul(
+                    Polynomial::new(input.as_slice(), polynomial_size),
+                    monomial_degree,
+                );
+            assert_eq!(div(&product, monomial_degree), input);
+        }
+    }
+
+    fn assert_close(actual: &[u64], expected: &[u64], log2_tolerance: u32) {
+        for (a, b) in zip_eq(actual, expected) {
+            let diff = a.wrapping_sub(*b) as i64;
+            assert!(diff.unsigned_abs() < 1 << log2_tolerance);
+        }
+    }
+
+    #[test]
+    fn fourier_round_trip() {
+        let mut csprng = SoftwareRandomGenerator::new(Seed(0));
+
+        for polynomial_size in [16, 256, 2048] {
+            let fft = Fft::new(polynomial_size);
+            let fft = fft.as_view();
+            let mut mem = GlobalMemBuffer::new(
+                StackReq::try_any_of([
+                    fft.forward_scratch().unwrap(),
+                    fft.backward_scratch().unwrap(),
+                ])
+                .unwrap(),
+            );
+            let mut stack = DynStack::new(&mut mem);
+
+            let input = random_coefficients(&mut csprng, polynomial_size);
+            let mut fourier = FourierPolynomial::new(vec![0.0; polynomial_size], polynomial_size);
+            let mut output = vec![0_u64; polynomial_size];
+
+            fourier.as_mut_view().forward_negacyclic_as_torus(
+                Polynomial::new(input.as_slice(), polynomial_size),
+                fft,
+                stack.rb_mut(),
+            );
+            fourier.as_view().backward_negacyclic_as_torus(
+                Polynomial::new(output.as_mut_slice(), polynomial_size),
+                fft,
+                stack.rb_mut(),
+            );
+
+            assert_close(&output, &input, 32);
+        }
+    }
+
+    #[test]
+    fn fft_mul_matches_schoolbook() {
+        let mut csprng = SoftwareRandomGenerator::new(Seed(0));
+
+        for polynomial_size in [16, 256, 2048] {
+            let fft = Fft::new(polynomial_size);
+            let fft = fft.as_view();
+            let mut mem = GlobalMemBuffer::new(fft_mul_scratch(fft).unwrap());
+
+            // small signed integer coefficients, in [-2^8, 2^8)
+            let lhs: Vec<u64> = random_coefficients(&mut csprng, polynomial_size)
+                .into_iter()
+                .map(|a| ((a as i64) >> 55) as u64)
+                .collect();
+            let rhs = random_coefficients(&mut csprng, polynomial_size);
+
+            let mut expected = vec![0_u64; polynomial_size];
+            Polynomial::new(expected.as_mut_slice(), polynomial_size).update_with_wrapping_mul(
+                Polynomial::new(lhs.as_slice(), polynomial_size),
+                Polynomial::new(rhs.as_slice(), polynomial_size),
+            );
+
+            let mut actual = vec![0_u64; polynomial_size];
+            Polynomial::new(actual.as_mut_slice(), polynomial_size).update_with_fft_mul(
+                Polynomial::new(lhs.as_slice(), polynomial_size),
+                Polynomial::new(rhs.as_slice(), polynomial_size),
+                fft,
+                DynStack::new(&mut mem),
+            );
+
+            assert_close(&actual, &expected, 40);
+        }
+    }
+
+    #[test]
+    fn wrapping_mul_matches_naive() {
+        let mut csprng = SoftwareRandomGenerator::new(Seed(0));
+
+        for polynomial_size in [1, 4, 8, 1024] {
+            let lhs = random_coefficients(&mut csprng, polynomial_size);
+            let rhs = random_coefficients(&mut csprng, polynomial_size);
+            let mut out = random_coefficients(&mut csprng, polynomial_size);
+
+            Polynomial::new(out.as_mut_slice(), polynomial_size).update_with_wrapping_mul(
+                Polynomial::new(lhs.as_slice(), polynomial_size),
+                Polynomial::new(rhs.as_slice(), polynomial_size),
+            );
+
+            assert_eq!(out, naive_negacyclic_mul(&lhs, &rhs));
+        }
+    }
+
+    #[test]
+    fn wrapping_dot_product_matches_naive() {
+        let mut csprng = SoftwareRandomGenerator::new(Seed(0));
+        let polynomial_size = 16;
+        let count = 3;
+
+        let lhs = random_coefficients(&mut csprng, polynomial_size * count);
+        let rhs = random_coefficients(&mut csprng, polynomial_size * count);
+        let initial = random_coefficients(&mut csprng, polynomial_size);
+
+        let mut expected = initial.clone();
+        for (lhs, rhs) in zip_eq(
+            lhs.chunks_exact(polynomial_size),
+            rhs.chunks_exact(polynomial_size),
+        ) {
+            for (e, p) in zip_eq(&mut expected, naive_negacyclic_mul(lhs, rhs)) {
+                *e = e.wrapping_add(p);
+            }
+        }
+
+        let mut actual = initial;
+        PolynomialList::new(
+            lhs.as_slice(),
+            PolynomialSize(polynomial_size),
+            PolynomialCount(count),
+        )
+        .wrapping_dot_product(
+            PolynomialList::new(
+                rhs.as_slice(),
+                PolynomialSize(polynomial_size),
+                PolynomialCount(count),
+            ),
+            &mut Polynomial::new(actual.as_mut_slice(), polynomial_size),
+        );
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn array_polynomial_mul_matches_vec() {
+        use crate::implementation::types::polynomial::ArrayPolynomial;
+
+        const N: usize = 256;
+        let mut csprng = SoftwareRandomGenerator::new(Seed(0));
+        let lhs = random_coefficients(&mut csprng, N);
+        let rhs = random_coefficients(&mut csprng, N);
+
+        let lhs_array = ArrayPolynomial::<N>::from_array(lhs.as_slice().try_into().unwrap());
+        let rhs_array = ArrayPolynomial::<N>::from_array(rhs.as_slice().try_into().unwrap());
+        let mut out_array = ArrayPolynomial::<N>::from_array([0; N]);
+        out_array
+            .as_mut_view()
+            .update_with_wrapping_mul(lhs_array.as_view(), rhs_array.as_view());
+
+        let mut out_vec = Polynomial::new(vec![0_u64; N], N);
+        out_vec.as_mut_view().update_with_wrapping_mul(
+            Polynomial::new(lhs.as_slice(), N),
+            Polynomial::new(rhs.as_slice(), N),
+        );
+
+        assert_eq!(out_array.into_data().as_slice(), out_vec.into_data());
+    }
+
+    #[test]
+    fn bit_reverse_in_place() {
+        let mut data: Vec<u64> = (0..8).collect();
+        super::bit_reverse_in_place(Polynomial::new(data.as_mut_slice(), 8));
+        assert_eq!(data, [0, 4, 2, 6, 1, 5, 3, 7]);
+        super::bit_reverse_in_place(Polynomial::new(data.as_mut_slice(), 8));
+        assert_eq!(data, (0..8).collect::<Vec<_>>());
+
+        let mut data: Vec<u64> = (0..1024).collect();
+        super::bit_reverse_in_place(Polynomial::new(data.as_mut_slice(), 1024));
+        assert_eq!(data[1], 512);
+        assert_eq!(data[3], 768);
+        super::bit_reverse_in_place(Polynomial::new(data.as_mut_slice(), 1024));
+        assert_eq!(data, (0..1024).collect::<Vec<_>>());
+
+        let mut data = [5_u64];
+        super::bit_reverse_in_place(Polynomial::new(data.as_mut_slice(), 1));
+        assert_eq!(data, [5]);
+    }
+
+    #[test]
+    #[should_panic(expected = "power of two")]
+    fn bit_reverse_in_place_checks_size() {
+        let mut data = [0_u64; 6];
+        super::bit_reverse_in_place(Polynomial::new(data.as_mut_slice(), 6));
+    }
+
+    #[test]
+    fn wrapping_eval() {
+        let eval = |coefficients: &[u64], x| {
+            Polynomial::new(coefficients, coefficients.len()).wrapping_eval(x)
+        };
+
+        assert_eq!(eval(&[], 3), 0);
+        assert_eq!(eval(&[7], 3), 7);
+        assert_eq!(eval(&[7, 5, 2], 0), 7);
+        // 7 + 5 * 3 + 2 * 9
+        assert_eq!(eval(&[7, 5, 2], 3), 40);
+        // 1 + 2^63 + 2^126 = 1 + 2^63 modulo 2^64
+        assert_eq!(eval(&[1, 1, 1], 1 << 63), 1 + (1 << 63));
+        assert_eq!(eval(&[0, 1], u64::MAX), u64::MAX);
+    }
+
+    #[test]
+    fn apply_automorphism() {
+        let input: Vec<u64> = (1..=8).collect();
+        let mut out = vec![0_u64; 8];
+
+        let mut polynomial = Polynomial::new(out.as_mut_slice(), 8);
+        polynomial.apply_automorphism(Polynomial::new(&input, 8), 1);
+        assert_eq!(out, input);
+
+        // X -> X^15 = X^-1, i.e. X^i -> -X^(8 - i) for i > 0
+        let mut polynomial = Polynomial::new(out.as_mut_slice(), 8);
+        polynomial.apply_automorphism(Polynomial::new(&input, 8), 15);
+        let neg = u64::wrapping_neg;
+        assert_eq!(
+            out,
+            [1, neg(8), neg(7), neg(6), neg(5), neg(4), neg(3), neg(2)]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "must be odd")]
+    fn apply_automorphism_even_index() {
+        let input = [0_u64; 8];
+        let mut out = [0_u64; 8];
+        Polynomial::new(out.as_mut_slice(), 8).apply_automorphism(Polynomial::new(&input, 8), 2);
+    }
+
+    #[test]
+    fn tensor_product_matches_naive() {
+        let mut csprng = SoftwareRandomGenerator::new(Seed(0));
+        let polynomial_size = 8;
+
+        let lhs = random_coefficients(&mut csprng, polynomial_size * 2);
+        let rhs = random_coefficients(&mut csprng, polynomial_size * 3);
+        let lhs_list = PolynomialList::new(
+            lhs.as_slice(),
+            PolynomialSize(polynomial_size),
+            PolynomialCount(2),
+        );
+        let rhs_list = PolynomialList::new(
+            rhs.as_slice(),
+            PolynomialSize(polynomial_size),
+            PolynomialCount(3),
+        );
+
+        let product = lhs_list.tensor_product(rhs_list.as_view());
+        assert_eq!(product.count, 6);
+        assert_eq!(product.polynomial_size, polynomial_size);
+
+        for (i, j) in [(0, 0), (0, 2), (1, 1)] {
+            assert_eq!(
+                product.get_polynomial(i * 3 + j).into_data(),
+                naive_negacyclic_mul(
+                    lhs_list.get_polynomial(i).into_data(),
+                    rhs_list.get_polynomial(j).into_data(),
+                ),
+            );
+        }
+    }
+
+    #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+    #[test]
+    fn wrapping_add_sub_avx2_matches_scalar() {
+        let Some(simd) = x86::Avx2::try_new() else {
+            return;
+        };
+        let mut csprng = SoftwareRandomGenerator::new(Seed(0));
+
+        // 1027 is not a multiple of the SIMD width, so the scalar tail is also exercised
+        for len in [1, 4, 1024, 1027] {
+            let lhs = random_coefficients(&mut csprng, len);
+            let rhs = random_coefficients(&mut csprng, len);
+
+            let mut scalar = lhs.clone();
+            let mut simd_out = lhs.clone();
+            wrapping_add_scalar(&mut scalar, &rhs);
+            x86::wrapping_add_avx2(simd, &mut simd_out, &rhs);
+            assert_eq!(scalar, simd_out);
+
+            let mut scalar = lhs.clone();
+            let mut simd_out = lhs;
+            wrapping_sub_scalar(&mut scalar, &rhs);
+            x86::wrapping_sub_avx2(simd, &mut simd_out, &rhs);
+            assert_eq!(scalar, simd_out);
+        }
+    }
+
+    #[test]
+    fn batch_forward_negacyclic_matches_single() {
+        let mut csprng = SoftwareRandomGenerator::new(Seed(0));
+        let polynomial_size = 256;
+        let count = 5;
+
+        let fft = Fft::new(polynomial_size);
+        let fft = fft.as_view();
+        let mut mem = GlobalMemBuffer::new(batch_forward_negacyclic_scratch(fft).unwrap());
+        let mut stack = DynStack::new(&mut mem);
+
+        let input = random_coefficients(&mut csprng, polynomial_size * count);
+        let input = PolynomialList::new(
+            input.as_slice(),
+            PolynomialSize(polynomial_size),
+            PolynomialCount(count),
+        );
+
+        let mut batched =
+            vec![0.0; FourierPolynomialList::<&[f64]>::data_len(polynomial_size, count)];
+        batch_forward_negacyclic(
+            input.as_view(),
+            FourierPolynomialList::new(
+                batched.as_mut_slice(),
+                PolynomialSize(polynomial_size),
+                PolynomialCount(count),
+            ),
+            fft,
+            stack.rb_mut(),
+        );
+
+        let batched = FourierPolynomialList::new(
+            batched.as_slice(),
+            PolynomialSize(polynomial_size),
+            PolynomialCount(count),
+        );
+        for (batched, standard) in zip_eq(batched.iter_polynomial(), input.iter_polynomial()) {
+            let mut single = vec![0.0; polynomial_size];
+            FourierPolynomial::new(single.as_mut_slice(), polynomial_size)
+                .forward_negacyclic_as_torus(standard, fft, stack.rb_mut());
+            assert_eq!(batched.into_data(), single.as_slice());
+        }
+
+        #[cfg(feature = "parallel")]
+        {
+            let mut parallel = vec![0.0; batched.data.len()];
+            batch_forward_negacyclic_par(
+                input.as_view(),
+                FourierPolynomialList::new(
+                    parallel.as_mut_slice(),
+                    PolynomialSize(polynomial_size),
+                    PolynomialCount(count),
+                ),
+                fft,
+            );
+            assert_eq!(parallel.as_slice(), batched.data);
+        }
+    }
+
+    #[test]
+    fn karatsuba_mul_matches_schoolbook() {
+        let mut csprng = SoftwareRandomGenerator::new(Seed(0));
+
+        for polynomial_size in [1, 31, 64, 100, 1024] {
+            let lhs = random_coefficients(&mut csprng, polynomial_size);
+            let rhs = random_coefficients(&mut csprng, polynomial_size);
+
+            let mut expected = vec![0_u64; polynomial_size];
+            Polynomial::new(expected.as_mut_slice(), polynomial_size).update_with_wrapping_mul(
+                Polynomial::new(lhs.as_slice(), polynomial_size),
+                Polynomial::new(rhs.as_slice(), polynomial_size),
+            );
+
+            let mut actual = vec![0_u64; polynomial_size];
+            Polynomial::new(actual.as_mut_slice(), polynomial_size)
+                .update_with_wrapping_karatsuba_mul(
+                    Polynomial::new(lhs.as_slice(), polynomial_size),
+                    Polynomial::new(rhs.as_slice(), polynomial_size),
+                );
+
+            assert_eq!(actual, expected);
+        }
+    }
+}
@@ -54,6 +54,7 @@ impl<C: Container> LweCiphertext<C> {
 
 pub mod test {
     use super::*;
+    use alloc::vec::Vec;
 
     impl LweCiphertext<Vec<u64>> {
         pub fn zero(dim: usize) -> Self {
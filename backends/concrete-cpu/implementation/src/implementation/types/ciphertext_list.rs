@@ -57,22 +57,105 @@ impl<C: Container> LweCiphertextList<C> {
     pub fn into_data(self) -> C {
         self.data
     }
-}
 
-impl LweCiphertextList<&mut [u64]> {
+    /// Number of elements of each ciphertext, mask and body, i.e. `lwe_dimension + 1`.
+    pub fn lwe_size(&self) -> usize {
+        self.lwe_dimension + 1
+    }
+
+    /// Returns the `index`-th ciphertext of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index >= count`.
+    pub fn get_ciphertext(&self, index: usize) -> LweCiphertext<&[C::Item]> {
+        self.assert_ciphertext_index(index);
+        let lwe_size = self.lwe_size();
+        LweCiphertext::new(
+            &self.data.as_ref()[index * lwe_size..(index + 1) * lwe_size],
+            self.lwe_dimension,
+        )
+    }
+
+    /// Returns the `index`-th ciphertext of the list, mutably.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index >= count`.
+    pub fn get_ciphertext_mut(&mut self, index: usize) -> LweCiphertext<&mut [C::Item]>
+    where
+        C: ContainerMut,
+    {
+        self.assert_ciphertext_index(index);
+        let lwe_size = self.lwe_size();
+        LweCiphertext::new(
+            &mut self.data.as_mut()[index * lwe_size..(index + 1) * lwe_size],
+            self.lwe_dimension,
+        )
+    }
+
+    fn assert_ciphertext_index(&self, index: usize) {
+        assert!(
+            index < self.count,
+            "ciphertext index out of range: index is {index} but count is {}",
+            self.count,
+        );
+    }
+
+    pub fn ciphertext_iter(&self) -> impl DoubleEndedIterator<Item = LweCiphertext<&[C::Item]>> {
+        let lwe_dimension = self.lwe_dimension;
+        self.data
+            .as_ref()
+            .chunks_exact(self.lwe_size())
+            .map(move |data| LweCiphertext::new(data, lwe_dimension))
+    }
+
     pub fn ciphertext_iter_mut(
         &mut self,
-    ) -> impl DoubleEndedIterator<Item = LweCiphertext<&mut [u64]>> {
+    ) -> impl DoubleEndedIterator<Item = LweCiphertext<&mut [C::Item]>>
+    where
+        C: ContainerMut,
+    {
+        let lwe_dimension = self.lwe_dimension;
+        let lwe_size = self.lwe_size();
         self.data
-            .chunks_exact_mut(self.lwe_dimension + 1)
-            .map(|data| LweCiphertext::new(data, self.lwe_dimension))
+            .as_mut()
+            .chunks_exact_mut(lwe_size)
+            .map(move |data| LweCiphertext::new(data, lwe_dimension))
     }
 }
 
-impl LweCiphertextList<&[u64]> {
-    pub fn ciphertext_iter(&self) -> impl DoubleEndedIterator<Item = LweCiphertext<&[u64]>> {
-        self.data
-            .chunks_exact(self.lwe_dimension + 1)
-            .map(|data| LweCiphertext::new(data, self.lwe_dimension))
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn iterate_and_index() {
+        let lwe_dimension = 3;
+        let mut data = vec![0_u64; 4 * 5];
+        let mut list = LweCiphertextList::new(data.as_mut_slice(), lwe_dimension, 5);
+        assert_eq!(list.lwe_size(), 4);
+
+        for (i, ct) in list.ciphertext_iter_mut().enumerate() {
+            // the body is the last element
+            *ct.into_data().last_mut().unwrap() = i as u64;
+        }
+        list.get_ciphertext_mut(1).into_data()[0] = 7;
+
+        let list = list.as_view();
+        assert_eq!(list.ciphertext_iter().count(), 5);
+        for (i, ct) in list.ciphertext_iter().enumerate() {
+            assert_eq!(ct.lwe_dimension, lwe_dimension);
+            assert_eq!(ct.into_data()[lwe_dimension], i as u64);
+        }
+        assert_eq!(list.get_ciphertext(1).into_data(), [7, 0, 0, 1]);
+        assert_eq!(list.get_ciphertext(4).into_data(), [0, 0, 0, 4]);
+    }
+
+    #[test]
+    #[should_panic(expected = "index is 2 but count is 2")]
+    fn get_ciphertext_out_of_range() {
+        let list = LweCiphertextList::new(vec![0_u64; 8], 3, 2);
+        list.get_ciphertext(2);
     }
 }
@@ -0,0 +1,57 @@
+use crate::implementation::{Container, ContainerMut, Split};
+
+/// Polynomial in the Fourier domain.
+///
+/// A polynomial of size `N` is represented by its `N/2` complex coefficients after a negacyclic
+/// FFT, stored as interleaved real and imaginary parts, for a total of `N` `f64` values.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[readonly::make]
+pub struct FourierPolynomial<C: Container<Item = f64>> {
+    pub data: C,
+    pub polynomial_size: usize,
+}
+
+impl<C: Container<Item = f64>> FourierPolynomial<C> {
+    pub fn data_len(polynomial_size: usize) -> usize {
+        polynomial_size
+    }
+
+    pub fn new(data: C, polynomial_size: usize) -> Self {
+        debug_assert_eq!(data.len(), Self::data_len(polynomial_size));
+        Self {
+            data,
+            polynomial_size,
+        }
+    }
+
+    pub unsafe fn from_raw_parts(data: C::Pointer, polynomial_size: usize) -> Self
+    where
+        C: Split,
+    {
+        Self {
+            data: C::from_raw_parts(data, Self::data_len(polynomial_size)),
+            polynomial_size,
+        }
+    }
+
+    pub fn as_view(&self) -> FourierPolynomial<&[f64]> {
+        FourierPolynomial {
+            data: self.data.as_ref(),
+            polynomial_size: self.polynomial_size,
+        }
+    }
+
+    pub fn as_mut_view(&mut self) -> FourierPolynomial<&mut [f64]>
+    where
+        C: ContainerMut,
+    {
+        FourierPolynomial {
+            data: self.data.as_mut(),
+            polynomial_size: self.polynomial_size,
+        }
+    }
+
+    pub fn into_data(self) -> C {
+        self.data
+    }
+}
@@ -0,0 +1,94 @@
+use crate::implementation::{Container, ContainerMut, Split};
+
+use super::fourier_polynomial::FourierPolynomial;
+use super::{PolynomialCount, PolynomialSize};
+
+/// List of polynomials in the Fourier domain.
+///
+/// Each polynomial of size `N` takes `N` `f64` values, i.e. `N/2` interleaved complex
+/// coefficients, see [`FourierPolynomial`].
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[readonly::make]
+pub struct FourierPolynomialList<C: Container<Item = f64>> {
+    pub data: C,
+    pub polynomial_size: usize,
+    pub count: usize,
+}
+
+impl<C: Container<Item = f64>> FourierPolynomialList<C> {
+    pub fn data_len(polynomial_size: usize, count: usize) -> usize {
+        FourierPolynomial::<C>::data_len(polynomial_size) * count
+    }
+
+    pub fn new(
+        data: C,
+        PolynomialSize(polynomial_size): PolynomialSize,
+        PolynomialCount(count): PolynomialCount,
+    ) -> Self {
+        debug_assert_eq!(data.len(), Self::data_len(polynomial_size, count));
+        Self {
+            data,
+            polynomial_size,
+            count,
+        }
+    }
+
+    pub unsafe fn from_raw_parts(
+        data: C::Pointer,
+        PolynomialSize(polynomial_size): PolynomialSize,
+        PolynomialCount(count): PolynomialCount,
+    ) -> Self
+    where
+        C: Split,
+    {
+        Self {
+            data: C::from_raw_parts(data, Self::data_len(polynomial_size, count)),
+            polynomial_size,
+            count,
+        }
+    }
+
+    pub fn as_view(&self) -> FourierPolynomialList<&[f64]> {
+        FourierPolynomialList {
+            data: self.data.as_ref(),
+            polynomial_size: self.polynomial_size,
+            count: self.count,
+        }
+    }
+
+    pub fn as_mut_view(&mut self) -> FourierPolynomialList<&mut [f64]>
+    where
+        C: ContainerMut,
+    {
+        FourierPolynomialList {
+            data: self.data.as_mut(),
+            polynomial_size: self.polynomial_size,
+            count: self.count,
+        }
+    }
+
+    pub fn into_data(self) -> C {
+        self.data
+    }
+
+    pub fn iter_polynomial(&self) -> impl DoubleEndedIterator<Item = FourierPolynomial<&'_ [f64]>> {
+        let polynomial_size = self.polynomial_size;
+        self.data
+            .as_ref()
+            .chunks_exact(FourierPolynomial::<C>::data_len(polynomial_size))
+            .map(move |a| FourierPolynomial::new(a, polynomial_size))
+    }
+
+    pub fn iter_polynomial_mut(
+        &mut self,
+    ) -> impl DoubleEndedIterator<Item = FourierPolynomial<&'_ mut [f64]>>
+    where
+        C: ContainerMut,
+    {
+        let polynomial_size = self.polynomial_size;
+        self.data
+            .as_mut()
+            .chunks_exact_mut(FourierPolynomial::<C>::data_len(polynomial_size))
+            .map(move |a| FourierPolynomial::new(a, polynomial_size))
+    }
+}
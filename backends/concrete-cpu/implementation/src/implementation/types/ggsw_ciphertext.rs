@@ -1,7 +1,32 @@
 use crate::implementation::{Container, ContainerMut, Split};
 
+use super::polynomial_list::PolynomialList;
 use super::{DecompParams, GlweCiphertext, GlweParams};
 
+/// Error returned by [`GgswCiphertext::from_polynomial_list`] when the number of polynomials does
+/// not match the shape of the ciphertext.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ShapeError {
+    pub glwe_dimension: usize,
+    pub level_count: usize,
+    /// `(glwe_dimension + 1) * (glwe_dimension + 1) * level_count`.
+    pub expected_count: usize,
+    pub count: usize,
+}
+
+impl core::fmt::Display for ShapeError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "a GGSW ciphertext of GLWE dimension {} with {} levels has {} polynomials, got {}",
+            self.glwe_dimension, self.level_count, self.expected_count, self.count,
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ShapeError {}
+
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 #[readonly::make]
 pub struct GgswLevelMatrix<C: Container> {
@@ -98,6 +123,32 @@ impl<C: Container> GgswCiphertext<C> {
         }
     }
 
+    /// Creates a GGSW ciphertext over the polynomials of `list`, checking that there are
+    /// `glwe_dimension + 1` rows of `glwe_dimension + 1` polynomials for each of the
+    /// `decomp_params.level` levels.
+    pub fn from_polynomial_list(
+        list: PolynomialList<C>,
+        glwe_dimension: usize,
+        decomp_params: DecompParams,
+    ) -> Result<Self, ShapeError> {
+        let glwe_size = glwe_dimension + 1;
+        let expected_count = glwe_size * glwe_size * decomp_params.level;
+        if list.count != expected_count {
+            return Err(ShapeError {
+                glwe_dimension,
+                level_count: decomp_params.level,
+                expected_count,
+                count: list.count,
+            });
+        }
+
+        let glwe_params = GlweParams {
+            dimension: glwe_dimension,
+            polynomial_size: list.polynomial_size,
+        };
+        Ok(Self::new(list.into_data(), glwe_params, decomp_params))
+    }
+
     pub unsafe fn from_raw_parts(
         data: C::Pointer,
         glwe_params: GlweParams,
@@ -147,3 +198,47 @@ impl<C: Container> GgswCiphertext<C> {
             .map(move |(i, slice)| GgswLevelMatrix::new(slice, self.glwe_params, i + 1))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::implementation::types::{PolynomialCount, PolynomialSize};
+
+    const DECOMP_PARAMS: DecompParams = DecompParams {
+        level: 3,
+        base_log: 10,
+    };
+
+    #[test]
+    fn from_polynomial_list() {
+        let list =
+            PolynomialList::<Vec<u64>>::new_zeroed(PolynomialSize(8), PolynomialCount(2 * 2 * 3));
+        let ggsw = GgswCiphertext::from_polynomial_list(list, 1, DECOMP_PARAMS).unwrap();
+        assert_eq!(
+            ggsw.glwe_params,
+            GlweParams {
+                dimension: 1,
+                polynomial_size: 8,
+            },
+        );
+        assert_eq!(ggsw.decomp_params, DECOMP_PARAMS);
+        assert_eq!(ggsw.as_view().into_level_matrices_iter().count(), 3);
+    }
+
+    #[test]
+    fn from_polynomial_list_wrong_count() {
+        let list = PolynomialList::<Vec<u64>>::new_zeroed(
+            PolynomialSize(8),
+            PolynomialCount(2 * 2 * 3 - 1),
+        );
+        assert_eq!(
+            GgswCiphertext::from_polynomial_list(list, 1, DECOMP_PARAMS).unwrap_err(),
+            ShapeError {
+                glwe_dimension: 1,
+                level_count: 3,
+                expected_count: 12,
+                count: 11,
+            },
+        );
+    }
+}
@@ -1,8 +1,13 @@
+use alloc::vec::Vec;
+
 use super::polynomial::Polynomial;
 use super::polynomial_list::PolynomialList;
-use super::GlweParams;
+use super::{GlweParams, PolynomialCount, PolynomialSize};
 use crate::implementation::{zip_eq, Container, ContainerMut, Split};
 
+/// Mask polynomials and body polynomial of a [`GlweCiphertext`].
+pub type GlweMaskAndBody<C> = (PolynomialList<C>, Polynomial<C>);
+
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 #[readonly::make]
 pub struct GlweCiphertext<C: Container> {
@@ -59,7 +64,7 @@ impl<C: Container> GlweCiphertext<C> {
         self.data
     }
 
-    pub fn into_mask_and_body(self) -> (PolynomialList<C>, Polynomial<C>)
+    pub fn into_mask_and_body(self) -> GlweMaskAndBody<C>
     where
         C: Split,
     {
@@ -70,8 +75,8 @@ impl<C: Container> GlweCiphertext<C> {
         (
             PolynomialList::new(
                 mask,
-                self.glwe_params.polynomial_size,
-                self.glwe_params.dimension,
+                PolynomialSize(self.glwe_params.polynomial_size),
+                PolynomialCount(self.glwe_params.dimension),
             ),
             Polynomial::new(body, self.glwe_params.polynomial_size),
         )
@@ -82,6 +87,61 @@ impl<C: Container> GlweCiphertext<C> {
     {
         self.into_mask_and_body().1
     }
+
+    /// Returns the `glwe_dimension` mask polynomials.
+    pub fn get_mask(&self) -> PolynomialList<&[C::Item]> {
+        self.as_view().into_mask_and_body().0
+    }
+
+    /// Returns the body polynomial.
+    pub fn get_body(&self) -> Polynomial<&[C::Item]> {
+        self.as_view().into_body()
+    }
+
+    /// Returns the `glwe_dimension` mask polynomials, mutably.
+    pub fn get_mask_mut(&mut self) -> PolynomialList<&mut [C::Item]>
+    where
+        C: ContainerMut,
+    {
+        self.as_mut_view().into_mask_and_body().0
+    }
+
+    /// Returns the body polynomial, mutably.
+    pub fn get_body_mut(&mut self) -> Polynomial<&mut [C::Item]>
+    where
+        C: ContainerMut,
+    {
+        self.as_mut_view().into_body()
+    }
+
+    /// Returns the mask and the body, mutably, so that both can be written at the same time.
+    pub fn get_mask_and_body_mut(&mut self) -> GlweMaskAndBody<&mut [C::Item]>
+    where
+        C: ContainerMut,
+    {
+        self.as_mut_view().into_mask_and_body()
+    }
+}
+
+impl GlweCiphertext<Vec<u64>> {
+    /// Allocates the trivial encryption of `body`, i.e. a ciphertext with a zero mask, which any
+    /// secret key decrypts to `body` without noise.
+    pub fn new_trivial_from_polynomial(body: Polynomial<&[u64]>, glwe_dimension: usize) -> Self {
+        let mut glwe = Self::new_trivial_zero(glwe_dimension, body.polynomial_size);
+        glwe.get_body_mut()
+            .into_data()
+            .copy_from_slice(body.into_data());
+        glwe
+    }
+
+    /// Allocates the trivial encryption of zero, with all the coefficients set to zero.
+    pub fn new_trivial_zero(glwe_dimension: usize, polynomial_size: usize) -> Self {
+        let glwe_params = GlweParams {
+            dimension: glwe_dimension,
+            polynomial_size,
+        };
+        Self::new(vec![0; Self::data_len(glwe_params)], glwe_params)
+    }
 }
 
 impl GlweCiphertext<&mut [u64]> {
@@ -95,3 +155,63 @@ impl GlweCiphertext<&mut [u64]> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::c_api::types::tests::to_generic;
+    use crate::implementation::types::{GlweSecretKey, LweSecretKey};
+    use concrete_csprng::generators::{RandomGenerator, SoftwareRandomGenerator};
+    use concrete_csprng::seeders::Seed;
+
+    #[test]
+    fn trivial_encryption_decrypts_to_body() {
+        let mut csprng = SoftwareRandomGenerator::new(Seed(0));
+        let glwe_params = GlweParams {
+            dimension: 2,
+            polynomial_size: 16,
+        };
+        let body: Vec<u64> = (0..16_u64)
+            .map(|i| i.wrapping_mul(0x9e37_79b9_7f4a_7c15))
+            .collect();
+
+        let glwe = GlweCiphertext::new_trivial_from_polynomial(Polynomial::new(&body, 16), 2);
+        assert_eq!(glwe.glwe_params, glwe_params);
+        assert!(glwe.get_mask().data.iter().all(|a| *a == 0));
+
+        for _ in 0..3 {
+            let sk = LweSecretKey::new_random(to_generic(&mut csprng), glwe_params.lwe_dimension());
+            let sk = GlweSecretKey::new(sk.data.as_slice(), glwe_params);
+            assert_eq!(sk.decrypt_glwe(glwe.as_view()), body);
+        }
+
+        let zero = GlweCiphertext::new_trivial_zero(2, 16);
+        assert_eq!(zero.glwe_params, glwe_params);
+        assert!(zero.data.iter().all(|a| *a == 0));
+    }
+
+    #[test]
+    fn mask_and_body_accessors() {
+        let glwe_params = GlweParams {
+            dimension: 2,
+            polynomial_size: 4,
+        };
+        let mut data: Vec<u64> = (0..12).collect();
+        let mut glwe = GlweCiphertext::new(data.as_mut_slice(), glwe_params);
+
+        assert_eq!(glwe.get_mask().count, 2);
+        assert_eq!(glwe.get_mask().data, (0..8).collect::<Vec<_>>());
+        assert_eq!(glwe.get_body().into_data(), [8, 9, 10, 11]);
+
+        glwe.get_body_mut().into_data().fill(0);
+        assert_eq!(glwe.get_mask().data, (0..8).collect::<Vec<_>>());
+        assert_eq!(glwe.get_body().into_data(), [0; 4]);
+
+        {
+            let (mut mask, mut body) = glwe.get_mask_and_body_mut();
+            body.as_mut_view().into_data()[0] = mask.data[7];
+            mask.fill_with_zero();
+        }
+        assert_eq!(data, [0, 0, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0]);
+    }
+}
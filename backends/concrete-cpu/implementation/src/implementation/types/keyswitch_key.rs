@@ -95,3 +95,31 @@ impl<C: Container> LweKeyswitchKey<C> {
             })
     }
 }
+
+pub mod test {
+    use super::*;
+    use alloc::vec::Vec;
+
+    impl LweKeyswitchKey<Vec<u64>> {
+        /// Allocates a key switching from `input_key` to `output_key`.
+        pub fn new_random(
+            csprng: CsprngMut,
+            input_key: LweSecretKey<&[u64]>,
+            output_key: LweSecretKey<&[u64]>,
+            decomp_params: DecompParams,
+            variance: f64,
+        ) -> Self {
+            let (input_dimension, output_dimension) =
+                (input_key.lwe_dimension, output_key.lwe_dimension);
+            let mut ksk = LweKeyswitchKey::new(
+                vec![0_u64; Self::data_len(output_dimension, decomp_params.level, input_dimension)],
+                output_dimension,
+                input_dimension,
+                decomp_params,
+            );
+            ksk.as_mut_view()
+                .fill_with_keyswitch_key(input_key, output_key, variance, csprng);
+            ksk
+        }
+    }
+}
@@ -71,6 +71,7 @@ impl LweSecretKey<&mut [u64]> {
 pub mod test {
     use super::*;
     use crate::implementation::types::CsprngMut;
+    use alloc::vec::Vec;
 
     impl LweSecretKey<Vec<u64>> {
         pub fn new_random(csprng: CsprngMut, dim: usize) -> Self {
@@ -16,6 +16,17 @@ impl GlweParams {
     }
 }
 
+/// Size of each polynomial of a [`PolynomialList`](polynomial_list::PolynomialList).
+///
+/// The constructors of the list take this and [`PolynomialCount`] rather than two bare `usize`s,
+/// so that the two dimensions cannot be passed in the wrong order.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PolynomialSize(pub usize);
+
+/// Number of polynomials of a [`PolynomialList`](polynomial_list::PolynomialList).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PolynomialCount(pub usize);
+
 pub fn int_log2(a: usize) -> usize {
     debug_assert!(a.is_power_of_two());
     (a as f64).log2().ceil() as usize
@@ -51,6 +62,8 @@ mod csprng;
 pub use csprng::*;
 
 pub mod ciphertext_list;
+pub mod fourier_polynomial;
+pub mod fourier_polynomial_list;
 pub mod glev_ciphertext;
 pub mod lev_ciphertext;
 pub mod polynomial;
@@ -124,7 +124,7 @@ impl PackingKeyswitchKeyList<&mut [u64]> {
             self.as_mut_view().into_ppksk_key(),
             output_glwe_key
                 .iter()
-                .chain(std::iter::once(last_polynomial)),
+                .chain(core::iter::once(last_polynomial)),
         ) {
             fpksk.fill_with_private_functional_packing_keyswitch_key(
                 input_lwe_key,
@@ -136,6 +136,8 @@ impl PackingKeyswitchKeyList<&mut [u64]> {
             );
         }
     }
+
+    #[cfg(feature = "parallel")]
     pub fn fill_with_fpksk_for_circuit_bootstrap_par(
         &mut self,
         input_lwe_key: &LweSecretKey<&[u64]>,
@@ -1,11 +1,41 @@
-use crate::implementation::Container;
+use super::polynomial_list::PolynomialList;
+use super::{PolynomialCount, PolynomialSize};
+use crate::implementation::{Container, ContainerMut};
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Polynomial<C: Container> {
     data: C,
     pub polynomial_size: usize,
 }
 
+#[cfg(feature = "serde")]
+impl<'de, C: Container + serde::Deserialize<'de>> serde::Deserialize<'de> for Polynomial<C> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(serde::Deserialize)]
+        #[serde(rename = "Polynomial")]
+        struct Raw<C> {
+            data: C,
+            polynomial_size: usize,
+        }
+
+        let Raw {
+            data,
+            polynomial_size,
+        } = Raw::<C>::deserialize(deserializer)?;
+        if data.len() != polynomial_size {
+            return Err(serde::de::Error::custom(format_args!(
+                "polynomial data length mismatch: expected {polynomial_size}, got {}",
+                data.len()
+            )));
+        }
+        Ok(Self {
+            data,
+            polynomial_size,
+        })
+    }
+}
+
 impl<C: Container> Polynomial<C> {
     pub fn new(data: C, polynomial_size: usize) -> Self {
         debug_assert_eq!(data.len(), polynomial_size);
@@ -19,13 +49,65 @@ impl<C: Container> Polynomial<C> {
         self.data.len()
     }
 
+    pub fn is_empty(&self) -> bool {
+        self.data.len() == 0
+    }
+
     pub fn into_data(self) -> C {
         self.data
     }
+
+    /// Returns a borrowed view of the polynomial, so that owned polynomials can be passed to
+    /// functions taking a `Polynomial<&[_]>`.
+    pub fn as_view(&self) -> Polynomial<&[C::Item]> {
+        Polynomial {
+            data: self.data.as_ref(),
+            polynomial_size: self.polynomial_size,
+        }
+    }
+
+    pub fn as_mut_view(&mut self) -> Polynomial<&mut [C::Item]>
+    where
+        C: ContainerMut,
+    {
+        Polynomial {
+            data: self.data.as_mut(),
+            polynomial_size: self.polynomial_size,
+        }
+    }
+
+    /// Returns a view over the coefficients of `self`, split into consecutive polynomials of size
+    /// `polynomial_size`. See [`PolynomialList::as_single_polynomial`] for the inverse.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `polynomial_size` is zero or does not divide the size of `self`.
+    pub fn as_polynomial_list(&self, polynomial_size: usize) -> PolynomialList<&[C::Item]> {
+        assert!(
+            polynomial_size != 0 && self.polynomial_size.is_multiple_of(polynomial_size),
+            "the polynomial size {polynomial_size} does not divide {}",
+            self.polynomial_size,
+        );
+        PolynomialList::new(
+            self.data.as_ref(),
+            PolynomialSize(polynomial_size),
+            PolynomialCount(self.polynomial_size / polynomial_size),
+        )
+    }
+}
+
+/// Polynomial of size `N` stored inline, which needs no allocation and can live on the stack. The
+/// arithmetic is available through [`Polynomial::as_view`] and [`Polynomial::as_mut_view`].
+pub type ArrayPolynomial<const N: usize, T = u64> = Polynomial<[T; N]>;
+
+impl<T, const N: usize> Polynomial<[T; N]> {
+    pub fn from_array(data: [T; N]) -> Self {
+        Self::new(data, N)
+    }
 }
 
-impl<'a> Polynomial<&'a [u64]> {
-    pub fn iter(self) -> impl DoubleEndedIterator<Item = &'a u64> {
+impl<'a, T> Polynomial<&'a [T]> {
+    pub fn iter(self) -> impl DoubleEndedIterator<Item = &'a T> {
         self.data.iter()
     }
 
@@ -37,15 +119,38 @@ impl<'a> Polynomial<&'a [u64]> {
     }
 }
 
-impl<'a> Polynomial<&'a mut [u64]> {
-    fn iter(self) -> impl DoubleEndedIterator<Item = &'a mut u64> {
+impl<'a, T> Polynomial<&'a mut [T]> {
+    fn iter(self) -> impl DoubleEndedIterator<Item = &'a mut T> {
         self.data.iter_mut()
     }
+}
 
-    pub fn as_mut_view(&mut self) -> Polynomial<&mut [u64]> {
-        Polynomial {
-            data: self.data,
-            polynomial_size: self.polynomial_size,
-        }
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec::Vec;
+
+    fn coefficient_sum(polynomial: Polynomial<&[u64]>) -> u64 {
+        polynomial.iter().copied().fold(0, u64::wrapping_add)
+    }
+
+    #[test]
+    fn array_polynomial() {
+        let mut polynomial = ArrayPolynomial::<4>::from_array([1, 2, 3, 4]);
+        assert_eq!(polynomial.polynomial_size, 4);
+        assert_eq!(coefficient_sum(polynomial.as_view()), 10);
+
+        polynomial.as_mut_view().into_data()[3] = 0;
+        assert_eq!(polynomial.into_data(), [1, 2, 3, 0]);
+    }
+
+    #[test]
+    fn owned_polynomial_as_view() {
+        let mut polynomial = Polynomial::new((1..=8).collect::<Vec<u64>>(), 8);
+        assert_eq!(coefficient_sum(polynomial.as_view()), 36);
+
+        polynomial.as_mut_view().into_data()[0] = 10;
+        assert_eq!(coefficient_sum(polynomial.as_view()), 45);
+        assert_eq!(polynomial.into_data()[0], 10);
     }
 }
@@ -1,17 +1,157 @@
-use crate::implementation::Container;
+use crate::implementation::{zip_eq, Container, ContainerMut, ContainerOwned};
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::ops::{Bound, RangeBounds};
+#[cfg(feature = "parallel")]
+use rayon::{
+    prelude::{IndexedParallelIterator, ParallelIterator},
+    slice::{ParallelSlice, ParallelSliceMut},
+};
 
 use super::polynomial::Polynomial;
+use super::{PolynomialCount, PolynomialSize};
+
+/// Error returned by the fallible constructors of [`PolynomialList`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum PolynomialListError {
+    /// The length of the container does not match `polynomial_size * count`.
+    LengthMismatch { expected: usize, got: usize },
+    /// `polynomial_size * count` does not fit in a `usize`.
+    SizeOverflow {
+        polynomial_size: usize,
+        count: usize,
+    },
+    /// The length of the container is not a multiple of the given dimension, or that dimension
+    /// is zero.
+    NotDivisible { len: usize, divisor: usize },
+}
+
+impl core::fmt::Display for PolynomialListError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            PolynomialListError::LengthMismatch { expected, got } => write!(
+                f,
+                "polynomial list data length mismatch: expected {expected}, got {got}"
+            ),
+            PolynomialListError::SizeOverflow {
+                polynomial_size,
+                count,
+            } => write!(
+                f,
+                "polynomial list size overflow: {count} polynomials of size {polynomial_size}"
+            ),
+            PolynomialListError::NotDivisible { len, divisor } => write!(
+                f,
+                "polynomial list data length {len} is not divisible by {divisor}"
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for PolynomialListError {}
+
+/// Error returned by [`PolynomialList::from_bytes`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum DeserError {
+    /// The input does not start with the magic bytes of the format, or with a prefix of them.
+    BadMagic,
+    /// The format version is not supported by this version of the library.
+    UnsupportedVersion(u8),
+    /// The input ends before the header or the coefficients are complete.
+    Truncated { expected: usize, got: usize },
+    /// The input is longer than the header and the `polynomial_size * count` coefficients.
+    TrailingBytes { expected: usize, got: usize },
+    /// `polynomial_size * count` does not fit in a `usize`.
+    SizeOverflow { polynomial_size: u64, count: u64 },
+}
+
+impl core::fmt::Display for DeserError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            DeserError::BadMagic => f.write_str("not a serialized polynomial list: bad magic"),
+            DeserError::UnsupportedVersion(version) => {
+                write!(f, "unsupported polynomial list format version {version}")
+            }
+            DeserError::Truncated { expected, got } => write!(
+                f,
+                "truncated polynomial list: expected {expected} bytes, got {got}"
+            ),
+            DeserError::TrailingBytes { expected, got } => write!(
+                f,
+                "trailing bytes after polynomial list: expected {expected} bytes, got {got}"
+            ),
+            DeserError::SizeOverflow {
+                polynomial_size,
+                count,
+            } => write!(
+                f,
+                "polynomial list size overflow: {count} polynomials of size {polynomial_size}"
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DeserError {}
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct PolynomialList<C: Container> {
     pub data: C,
     pub count: usize,
     pub polynomial_size: usize,
 }
 
+/// Returns `polynomial_size * count`, or an error if it overflows a `usize`, which can happen on
+/// 32-bit targets.
+fn checked_data_len(polynomial_size: usize, count: usize) -> Result<usize, PolynomialListError> {
+    polynomial_size
+        .checked_mul(count)
+        .ok_or(PolynomialListError::SizeOverflow {
+            polynomial_size,
+            count,
+        })
+}
+
+/// Same as [`checked_data_len`], but panics on overflow.
+fn data_len(polynomial_size: usize, count: usize) -> usize {
+    match checked_data_len(polynomial_size, count) {
+        Ok(len) => len,
+        Err(_) => panic!("size overflow: {count} polynomials of size {polynomial_size}"),
+    }
+}
+
 impl<C: Container> PolynomialList<C> {
-    pub fn new(data: C, polynomial_size: usize, count: usize) -> Self {
-        debug_assert_eq!(data.len(), polynomial_size * count);
+    /// # Panics
+    ///
+    /// Panics if `polynomial_size * count` overflows a `usize`.
+    pub fn new(
+        data: C,
+        PolynomialSize(polynomial_size): PolynomialSize,
+        PolynomialCount(count): PolynomialCount,
+    ) -> Self {
+        let len = data_len(polynomial_size, count);
+        debug_assert_eq!(data.len(), len);
+        Self {
+            data,
+            count,
+            polynomial_size,
+        }
+    }
+
+    /// Creates a new list without any check, not even in debug builds, for hot loops where the
+    /// shape is already known to be valid.
+    ///
+    /// # Safety
+    ///
+    /// `polynomial_size * count` must not overflow a `usize`, and `data.len()` must be equal to
+    /// `polynomial_size * count`.
+    pub unsafe fn new_unchecked(
+        data: C,
+        PolynomialSize(polynomial_size): PolynomialSize,
+        PolynomialCount(count): PolynomialCount,
+    ) -> Self {
         Self {
             data,
             count,
@@ -19,70 +159,2219 @@ impl<C: Container> PolynomialList<C> {
         }
     }
 
+    /// Creates a new list, checking that the container length is `polynomial_size * count`.
+    ///
+    /// Unlike [`PolynomialList::new`], the check is also performed in release builds, which makes
+    /// this the constructor to use for sizes coming from untrusted sources.
+    pub fn try_new(
+        data: C,
+        PolynomialSize(polynomial_size): PolynomialSize,
+        PolynomialCount(count): PolynomialCount,
+    ) -> Result<Self, PolynomialListError> {
+        let expected = checked_data_len(polynomial_size, count)?;
+        let got = data.len();
+        if got != expected {
+            return Err(PolynomialListError::LengthMismatch { expected, got });
+        }
+        Ok(Self {
+            data,
+            count,
+            polynomial_size,
+        })
+    }
+
+    /// Checks that the shape of the list is consistent with its container, i.e. that
+    /// `polynomial_size * count` does not overflow and is equal to `data.len()`.
+    ///
+    /// The fields are public, so this is meant to track down code that corrupted them. Checking the
+    /// product without wrapping also rejects dimensions that are too large for the data but whose
+    /// product wraps around to its length.
+    pub fn verify_invariants(&self) -> Result<(), PolynomialListError> {
+        let expected = checked_data_len(self.polynomial_size, self.count)?;
+        let got = self.data.len();
+        if got != expected {
+            return Err(PolynomialListError::LengthMismatch { expected, got });
+        }
+        Ok(())
+    }
+
     fn container_len(&self) -> usize {
         self.data.len()
     }
 
+    /// Returns the container, without wiping it when the `zeroize` feature is enabled.
     pub fn into_data(self) -> C {
+        let this = core::mem::ManuallyDrop::new(self);
+        // SAFETY: `this` is never dropped, so `data` is only moved out once.
+        unsafe { core::ptr::read(&this.data) }
+    }
+
+    pub fn as_view(&self) -> PolynomialList<&[C::Item]> {
+        PolynomialList {
+            data: self.data.as_ref(),
+            count: self.count,
+            polynomial_size: self.polynomial_size,
+        }
+    }
+
+    pub fn as_mut_view(&mut self) -> PolynomialList<&mut [C::Item]>
+    where
+        C: ContainerMut,
+    {
+        PolynomialList {
+            data: self.data.as_mut(),
+            count: self.count,
+            polynomial_size: self.polynomial_size,
+        }
+    }
+
+    /// Returns a view over all the coefficients of the list, as one polynomial of size
+    /// `count * polynomial_size`. See [`Polynomial::as_polynomial_list`] for the inverse.
+    pub fn as_single_polynomial(&self) -> Polynomial<&[C::Item]> {
+        Polynomial::new(self.data.as_ref(), self.container_len())
+    }
+
+    /// Returns the `index`-th polynomial of the list, or `None` if `index >= count`.
+    pub fn try_get_polynomial(&self, index: usize) -> Option<Polynomial<&[C::Item]>> {
+        if index >= self.count {
+            return None;
+        }
+        let polynomial_size = self.polynomial_size;
+        Some(Polynomial::new(
+            &self.data.as_ref()[index * polynomial_size..(index + 1) * polynomial_size],
+            polynomial_size,
+        ))
+    }
+
+    /// Returns the `index`-th polynomial of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index >= count`.
+    pub fn get_polynomial(&self, index: usize) -> Polynomial<&[C::Item]> {
+        self.assert_polynomial_index(index);
+        self.try_get_polynomial(index).unwrap()
+    }
+
+    /// Returns the `index`-th polynomial of the list, mutably.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index >= count`.
+    pub fn get_polynomial_mut(&mut self, index: usize) -> Polynomial<&mut [C::Item]>
+    where
+        C: ContainerMut,
+    {
+        self.assert_polynomial_index(index);
+        let polynomial_size = self.polynomial_size;
+        Polynomial::new(
+            &mut self.data.as_mut()[index * polynomial_size..(index + 1) * polynomial_size],
+            polynomial_size,
+        )
+    }
+
+    fn assert_polynomial_index(&self, index: usize) {
+        assert!(
+            index < self.count,
+            "polynomial index out of range: index is {index} but count is {}",
+            self.count,
+        );
+    }
+
+    pub fn iter_polynomial(&self) -> impl DoubleEndedIterator<Item = Polynomial<&'_ [C::Item]>> {
+        let polynomial_size = self.polynomial_size;
         self.data
+            .as_ref()
+            .chunks_exact(polynomial_size)
+            .map(move |a| Polynomial::new(a, polynomial_size))
     }
-}
 
-impl PolynomialList<&[u64]> {
-    pub fn iter_polynomial(&self) -> impl DoubleEndedIterator<Item = Polynomial<&'_ [u64]>> {
+    pub fn iter_polynomial_mut(
+        &mut self,
+    ) -> impl DoubleEndedIterator<Item = Polynomial<&'_ mut [C::Item]>>
+    where
+        C: ContainerMut,
+    {
+        let polynomial_size = self.polynomial_size;
         self.data
-            .chunks_exact(self.polynomial_size)
-            .map(|a| Polynomial::new(a, self.polynomial_size))
+            .as_mut()
+            .chunks_exact_mut(polynomial_size)
+            .map(move |a| Polynomial::new(a, polynomial_size))
     }
 
-    // Creates an iterator over borrowed sub-lists.
-    pub fn sublist_iter(
+    /// Same as [`PolynomialList::iter_polynomial`], with each polynomial paired with its index
+    /// in `0..self.count`.
+    pub fn enumerate_polynomial(
         &self,
-        count: usize,
-    ) -> impl DoubleEndedIterator<Item = PolynomialList<&[u64]>> {
+    ) -> impl DoubleEndedIterator<Item = (usize, Polynomial<&'_ [C::Item]>)> {
         let polynomial_size = self.polynomial_size;
+        self.data
+            .as_ref()
+            .chunks_exact(polynomial_size)
+            .map(move |a| Polynomial::new(a, polynomial_size))
+            .enumerate()
+    }
 
-        debug_assert_eq!(self.count % count, 0);
-
+    /// Same as [`PolynomialList::iter_polynomial_mut`], with each polynomial paired with its index
+    /// in `0..self.count`.
+    pub fn enumerate_polynomial_mut(
+        &mut self,
+    ) -> impl DoubleEndedIterator<Item = (usize, Polynomial<&'_ mut [C::Item]>)>
+    where
+        C: ContainerMut,
+    {
+        let polynomial_size = self.polynomial_size;
         self.data
-            .chunks_exact(count * polynomial_size)
-            .map(move |sub| PolynomialList {
-                data: sub,
-                polynomial_size,
-                count,
-            })
+            .as_mut()
+            .chunks_exact_mut(polynomial_size)
+            .map(move |a| Polynomial::new(a, polynomial_size))
+            .enumerate()
     }
-    pub fn as_view(&self) -> PolynomialList<&[u64]> {
-        PolynomialList {
-            data: self.data,
-            count: self.count,
-            polynomial_size: self.polynomial_size,
+
+    /// Returns `true` if `self` and `other` have the same shape and coefficients, whatever their
+    /// container types.
+    pub fn content_eq<C2: Container<Item = C::Item>>(&self, other: &PolynomialList<C2>) -> bool
+    where
+        C::Item: PartialEq,
+    {
+        self.count == other.count
+            && self.polynomial_size == other.polynomial_size
+            && self.data.as_ref() == other.data.as_ref()
+    }
+
+    /// Returns a copy of the list with polynomials of size `new_polynomial_size`.
+    ///
+    /// The lowest-degree `min(self.polynomial_size, new_polynomial_size)` coefficients of each
+    /// polynomial are kept. When growing, the higher-degree coefficients are zero; when shrinking,
+    /// they are dropped, without any reduction modulo `X^new_polynomial_size + 1`.
+    pub fn resized(&self, new_polynomial_size: usize) -> PolynomialList<Vec<C::Item>>
+    where
+        C::Item: Copy + Default,
+    {
+        let kept = self.polynomial_size.min(new_polynomial_size);
+        let mut data = Vec::with_capacity(data_len(new_polynomial_size, self.count));
+        for polynomial in self.iter_polynomial() {
+            data.extend_from_slice(&polynomial.into_data()[..kept]);
+            data.resize(
+                data.len() + (new_polynomial_size - kept),
+                C::Item::default(),
+            );
         }
+        PolynomialList::new(
+            data,
+            PolynomialSize(new_polynomial_size),
+            PolynomialCount(self.count),
+        )
     }
 }
 
-impl PolynomialList<&mut [u64]> {
-    pub fn iter_polynomial(
-        &mut self,
-    ) -> impl DoubleEndedIterator<Item = Polynomial<&'_ mut [u64]>> {
-        self.data
-            .chunks_exact_mut(self.polynomial_size)
-            .map(|a| Polynomial::new(a, self.polynomial_size))
+#[cfg(feature = "serde")]
+impl<'de, C: Container + serde::Deserialize<'de>> serde::Deserialize<'de> for PolynomialList<C> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        // The fields are deserialized as is, then checked for consistency before building the
+        // list.
+        #[derive(serde::Deserialize)]
+        #[serde(rename = "PolynomialList")]
+        struct Raw<C> {
+            data: C,
+            count: usize,
+            polynomial_size: usize,
+        }
+
+        let Raw {
+            data,
+            count,
+            polynomial_size,
+        } = Raw::deserialize(deserializer)?;
+        Self::try_new(
+            data,
+            PolynomialSize(polynomial_size),
+            PolynomialCount(count),
+        )
+        .map_err(serde::de::Error::custom)
     }
+}
 
-    pub fn as_mut_view(&mut self) -> PolynomialList<&mut [u64]> {
-        PolynomialList {
-            data: self.data,
-            count: self.count,
+/// Views as `count × polynomial_size` arrays, in C order, which is the layout of the list, so no
+/// coefficient is copied.
+#[cfg(feature = "ndarray")]
+impl<C: Container> PolynomialList<C> {
+    pub fn as_array2(&self) -> ndarray::ArrayView2<'_, C::Item> {
+        ndarray::ArrayView2::from_shape((self.count, self.polynomial_size), self.data.as_ref())
+            .expect("the data length matches the shape of the list")
+    }
+
+    pub fn as_array2_mut(&mut self) -> ndarray::ArrayViewMut2<'_, C::Item>
+    where
+        C: ContainerMut,
+    {
+        ndarray::ArrayViewMut2::from_shape((self.count, self.polynomial_size), self.data.as_mut())
+            .expect("the data length matches the shape of the list")
+    }
+}
+
+#[cfg(feature = "ndarray")]
+impl<T: Copy> PolynomialList<Vec<T>> {
+    /// Copies the rows of `array` into a list of `array.nrows()` polynomials of size
+    /// `array.ncols()`, whatever the memory layout of `array`.
+    pub fn from_array2(array: ndarray::ArrayView2<'_, T>) -> Self {
+        let (count, polynomial_size) = array.dim();
+        Self::new(
+            array.iter().copied().collect(),
+            PolynomialSize(polynomial_size),
+            PolynomialCount(count),
+        )
+    }
+}
+
+/// Only implemented for containers that own their data, since views do not own the buffer they
+/// point to.
+#[cfg(feature = "zeroize")]
+impl<C: Container + zeroize::Zeroize> zeroize::Zeroize for PolynomialList<C> {
+    fn zeroize(&mut self) {
+        self.data.zeroize();
+    }
+}
+
+/// Lists owning their data, e.g. in a `Vec<u64>`, overwrite it with zeros when dropped, so that
+/// secret keys do not linger in freed memory. Dropping a view leaves the data untouched.
+#[cfg(feature = "zeroize")]
+impl<C: Container> Drop for PolynomialList<C> {
+    fn drop(&mut self) {
+        // SAFETY: the data is not read after the list is dropped.
+        unsafe { self.data.zeroize_owned() };
+    }
+}
+
+/// Only claimed for elements that are wiped on drop: those with drop glue are left untouched.
+#[cfg(feature = "zeroize")]
+impl<T: zeroize::DefaultIsZeroes> zeroize::ZeroizeOnDrop for PolynomialList<Vec<T>> {}
+
+#[cfg(feature = "zeroize")]
+impl<T: zeroize::DefaultIsZeroes> zeroize::ZeroizeOnDrop for PolynomialList<Box<[T]>> {}
+
+impl<C: ContainerOwned> PolynomialList<C>
+where
+    C::Item: Default,
+{
+    /// Allocates a list of `count` polynomials with all coefficients set to the default value
+    /// (zero, for integers), in any owned container.
+    pub fn new_zeroed(
+        PolynomialSize(polynomial_size): PolynomialSize,
+        PolynomialCount(count): PolynomialCount,
+    ) -> Self {
+        let data = C::collect((0..data_len(polynomial_size, count)).map(|_| C::Item::default()));
+        Self::new(
+            data,
+            PolynomialSize(polynomial_size),
+            PolynomialCount(count),
+        )
+    }
+}
+
+impl<T: Copy> PolynomialList<Vec<T>> {
+    /// Collects `polynomials` into a new list, whose count is the number of polynomials that were
+    /// consumed. An empty iterator gives an empty list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a polynomial is not of size `polynomial_size`.
+    pub fn from_polynomials<'a>(
+        polynomials: impl Iterator<Item = Polynomial<&'a [T]>>,
+        polynomial_size: usize,
+    ) -> Self
+    where
+        T: 'a,
+    {
+        let mut list = Self::new(
+            Vec::new(),
+            PolynomialSize(polynomial_size),
+            PolynomialCount(0),
+        );
+        for polynomial in polynomials {
+            list.push_polynomial(polynomial);
+        }
+        list
+    }
+
+    /// Appends `polynomial` at the end of the list.
+    pub fn push_polynomial(&mut self, polynomial: Polynomial<&[T]>) {
+        assert_eq!(polynomial.polynomial_size, self.polynomial_size);
+        self.data.extend_from_slice(polynomial.into_data());
+        self.count += 1;
+    }
+
+    /// Appends all the polynomials of `other` at the end of the list.
+    ///
+    /// An empty list takes the polynomial size of `other`, so that a list can be assembled from
+    /// parts starting from `PolynomialList::new(Vec::new(), _, PolynomialCount(0))`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is not empty and the polynomial sizes differ.
+    pub fn extend_from_list(&mut self, other: PolynomialList<&[T]>) {
+        if self.count == 0 && self.data.is_empty() {
+            self.polynomial_size = other.polynomial_size;
+        }
+        assert_eq!(other.polynomial_size, self.polynomial_size);
+        self.data.extend_from_slice(other.data);
+        self.count += other.count;
+    }
+
+    /// Makes `self` a copy of `src`, including its shape, reusing the allocation of `self.data`
+    /// if its capacity is large enough.
+    pub fn clone_from_view(&mut self, src: PolynomialList<&[T]>) {
+        self.data.clear();
+        self.data.extend_from_slice(src.data);
+        self.polynomial_size = src.polynomial_size;
+        self.count = src.count;
+    }
+
+    /// Consumes the list and splits it into owned sub-lists of `count` polynomials each.
+    ///
+    /// Unlike [`PolynomialList::sublist_iter`], the sub-lists do not borrow from `self`, so they
+    /// can be moved to other threads.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `count` is zero or does not divide `self.count`.
+    pub fn into_sublists(self, count: usize) -> impl Iterator<Item = PolynomialList<Vec<T>>> {
+        assert_ne!(count, 0);
+        assert_eq!(self.count % count, 0);
+
+        let polynomial_size = self.polynomial_size;
+        let sublist_len = count * polynomial_size;
+        let sublist_count = self.count / count;
+
+        let mut data = self.into_data().into_iter();
+        (0..sublist_count).map(move |_| PolynomialList {
+            data: data.by_ref().take(sublist_len).collect(),
+            polynomial_size,
+            count,
+        })
+    }
+}
+
+/// Converts the container to a boxed slice, which cannot grow. The elements are not copied,
+/// although the buffer may be reallocated if the vector has spare capacity.
+impl<T> From<PolynomialList<Vec<T>>> for PolynomialList<Box<[T]>> {
+    fn from(list: PolynomialList<Vec<T>>) -> Self {
+        let (count, polynomial_size) = (list.count, list.polynomial_size);
+        Self {
+            data: list.into_data().into_boxed_slice(),
+            count,
+            polynomial_size,
+        }
+    }
+}
+
+// Not `pub`, so that cbindgen does not export them to the C header.
+const BYTES_MAGIC: [u8; 4] = *b"CCPL";
+const BYTES_VERSION: u8 = 1;
+const BYTES_HEADER_LEN: usize = 4 + 1 + 8 + 8;
+
+/// Versioned binary format, which unlike the serde implementations does not depend on the layout
+/// of the Rust types and is meant for data stored for a long time.
+///
+/// The format is, in order:
+/// - the 4 magic bytes `CCPL`,
+/// - the format version, one byte, currently 1,
+/// - `polynomial_size` and `count`, as little-endian `u64`,
+/// - the `polynomial_size * count` coefficients, as little-endian `u64`.
+impl<C: Container<Item = u64>> PolynomialList<C> {
+    /// Serializes the list, see the format above.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(BYTES_HEADER_LEN + 8 * self.data.len());
+        bytes.extend_from_slice(&BYTES_MAGIC);
+        bytes.push(BYTES_VERSION);
+        bytes.extend_from_slice(&(self.polynomial_size as u64).to_le_bytes());
+        bytes.extend_from_slice(&(self.count as u64).to_le_bytes());
+        for coefficient in self.data.as_ref() {
+            bytes.extend_from_slice(&coefficient.to_le_bytes());
+        }
+        bytes
+    }
+}
+
+impl PolynomialList<Vec<u64>> {
+    /// Deserializes a list written by [`PolynomialList::to_bytes`].
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, DeserError> {
+        let header_len = BYTES_HEADER_LEN;
+        // a prefix of the magic bytes is a truncated list, not a foreign format
+        let magic_len = bytes.len().min(BYTES_MAGIC.len());
+        if bytes[..magic_len] != BYTES_MAGIC[..magic_len] {
+            return Err(DeserError::BadMagic);
+        }
+        if bytes.len() < header_len {
+            return Err(DeserError::Truncated {
+                expected: header_len,
+                got: bytes.len(),
+            });
+        }
+        if bytes[4] != BYTES_VERSION {
+            return Err(DeserError::UnsupportedVersion(bytes[4]));
+        }
+
+        let read_u64 = |bytes: &[u8]| u64::from_le_bytes(bytes.try_into().unwrap());
+        let polynomial_size = read_u64(&bytes[5..13]);
+        let count = read_u64(&bytes[13..21]);
+
+        let overflow = DeserError::SizeOverflow {
+            polynomial_size,
+            count,
+        };
+        let expected = usize::try_from(polynomial_size)
+            .ok()
+            .zip(usize::try_from(count).ok())
+            .and_then(|(polynomial_size, count)| polynomial_size.checked_mul(count))
+            .and_then(|len| len.checked_mul(8))
+            .and_then(|len| len.checked_add(header_len))
+            .ok_or(overflow)?;
+
+        if bytes.len() < expected {
+            return Err(DeserError::Truncated {
+                expected,
+                got: bytes.len(),
+            });
+        }
+        if bytes.len() > expected {
+            return Err(DeserError::TrailingBytes {
+                expected,
+                got: bytes.len(),
+            });
+        }
+
+        let data = bytes[header_len..].chunks_exact(8).map(read_u64).collect();
+        Ok(Self::new(
+            data,
+            PolynomialSize(polynomial_size as usize),
+            PolynomialCount(count as usize),
+        ))
+    }
+}
+
+/// Reads polynomials of size `polynomial_size` from `reader` one at a time, for lists too large
+/// to be held in memory.
+///
+/// The input is the bare coefficients, as little-endian `u64`, without the header of
+/// [`PolynomialList::to_bytes`]. The iterator ends cleanly if the input ends on a polynomial
+/// boundary, and yields an [`UnexpectedEof`](std::io::ErrorKind::UnexpectedEof) error if it ends
+/// in the middle of a polynomial. It stops after the first error.
+///
+/// # Panics
+///
+/// Panics if `polynomial_size` is zero.
+#[cfg(feature = "std")]
+pub fn stream_polynomials<R: std::io::Read>(
+    mut reader: R,
+    polynomial_size: usize,
+) -> impl Iterator<Item = std::io::Result<Polynomial<Vec<u64>>>> {
+    use std::io::{Error, ErrorKind};
+
+    assert_ne!(polynomial_size, 0);
+    let mut bytes = vec![0_u8; 8 * polynomial_size];
+    let mut done = false;
+
+    core::iter::from_fn(move || {
+        if done {
+            return None;
+        }
+
+        let mut filled = 0;
+        while filled < bytes.len() {
+            match reader.read(&mut bytes[filled..]) {
+                Ok(0) => break,
+                Ok(n) => filled += n,
+                Err(e) if e.kind() == ErrorKind::Interrupted => {}
+                Err(e) => {
+                    done = true;
+                    return Some(Err(e));
+                }
+            }
+        }
+
+        if filled == 0 {
+            done = true;
+            return None;
+        }
+        if filled < bytes.len() {
+            done = true;
+            return Some(Err(Error::new(
+                ErrorKind::UnexpectedEof,
+                format!(
+                    "truncated polynomial: expected {} bytes, got {filled}",
+                    bytes.len()
+                ),
+            )));
+        }
+
+        let data = bytes
+            .chunks_exact(8)
+            .map(|bytes| u64::from_le_bytes(bytes.try_into().unwrap()))
+            .collect();
+        Some(Ok(Polynomial::new(data, polynomial_size)))
+    })
+}
+
+/// Random lists, for tests.
+///
+/// The coefficients are drawn uniformly from the given generator, which is meant to be a seeded,
+/// reproducible one. This must not be used to generate cryptographic keys.
+#[cfg(feature = "test-tools")]
+impl<C: ContainerMut<Item = u64>> PolynomialList<C> {
+    /// Overwrites every coefficient of the list with a uniformly random `u64`.
+    pub fn fill_with_random(&mut self, rng: &mut impl rand::RngCore) {
+        for coefficient in self.data.as_mut() {
+            *coefficient = rng.next_u64();
+        }
+    }
+}
+
+#[cfg(feature = "test-tools")]
+impl PolynomialList<Vec<u64>> {
+    /// Returns a list of `count` polynomials with uniformly random coefficients.
+    pub fn new_random(
+        PolynomialSize(polynomial_size): PolynomialSize,
+        PolynomialCount(count): PolynomialCount,
+        rng: &mut impl rand::RngCore,
+    ) -> Self {
+        let mut list = Self::new(
+            vec![0; data_len(polynomial_size, count)],
+            PolynomialSize(polynomial_size),
+            PolynomialCount(count),
+        );
+        list.fill_with_random(rng);
+        list
+    }
+}
+
+/// Concatenates `lists` into a single owned list.
+///
+/// # Panics
+///
+/// Panics if `lists` is empty, or if the lists do not all have the same polynomial size.
+pub fn concat<T: Copy>(lists: &[PolynomialList<&[T]>]) -> PolynomialList<Vec<T>> {
+    assert!(
+        !lists.is_empty(),
+        "cannot concatenate an empty slice of lists"
+    );
+    let polynomial_size = lists[0].polynomial_size;
+
+    let mut data = Vec::with_capacity(lists.iter().map(|list| list.data.len()).sum());
+    let mut count = 0;
+    for list in lists {
+        assert_eq!(list.polynomial_size, polynomial_size);
+        data.extend_from_slice(list.data);
+        count += list.count;
+    }
+
+    PolynomialList::new(
+        data,
+        PolynomialSize(polynomial_size),
+        PolynomialCount(count),
+    )
+}
+
+/// Number of coefficients printed at each end of a polynomial by
+/// [`PolynomialList::debug_polynomials`] when it is too large to be printed entirely.
+const DEBUG_EDGE_COEFFICIENTS: usize = 3;
+
+/// See [`PolynomialList::debug_polynomials`].
+pub struct DebugPolynomials<'a, T> {
+    list: PolynomialList<&'a [T]>,
+}
+
+impl<T: core::fmt::Debug> core::fmt::Display for DebugPolynomials<'_, T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let polynomial_size = self.list.polynomial_size;
+        let truncated = polynomial_size > 2 * DEBUG_EDGE_COEFFICIENTS + 1;
+
+        f.write_str("[")?;
+        for (i, polynomial) in self.list.iter_polynomial().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            let coefficients = polynomial.into_data();
+            f.write_str("[")?;
+            for (j, coefficient) in coefficients.iter().enumerate() {
+                if truncated
+                    && (DEBUG_EDGE_COEFFICIENTS..polynomial_size - DEBUG_EDGE_COEFFICIENTS)
+                        .contains(&j)
+                {
+                    if j == DEBUG_EDGE_COEFFICIENTS {
+                        f.write_str(", ..")?;
+                    }
+                    continue;
+                }
+                if j > 0 {
+                    f.write_str(", ")?;
+                }
+                write!(f, "{coefficient:?}")?;
+            }
+            f.write_str("]")?;
+        }
+        f.write_str("]")
+    }
+}
+
+/// Iterator over the polynomials of a borrowed [`PolynomialList`], which allows to write
+/// `for polynomial in &list`.
+pub struct Polynomials<'a, T> {
+    chunks: core::slice::ChunksExact<'a, T>,
+    polynomial_size: usize,
+}
+
+/// Iterator over the polynomials of a mutably borrowed [`PolynomialList`], which allows to write
+/// `for polynomial in &mut list`.
+pub struct PolynomialsMut<'a, T> {
+    chunks: core::slice::ChunksExactMut<'a, T>,
+    polynomial_size: usize,
+}
+
+impl<'a, T> Iterator for Polynomials<'a, T> {
+    type Item = Polynomial<&'a [T]>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let polynomial_size = self.polynomial_size;
+        self.chunks
+            .next()
+            .map(|a| Polynomial::new(a, polynomial_size))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.chunks.size_hint()
+    }
+}
+
+impl<T> DoubleEndedIterator for Polynomials<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let polynomial_size = self.polynomial_size;
+        self.chunks
+            .next_back()
+            .map(|a| Polynomial::new(a, polynomial_size))
+    }
+}
+
+impl<T> ExactSizeIterator for Polynomials<'_, T> {}
+
+impl<'a, T> Iterator for PolynomialsMut<'a, T> {
+    type Item = Polynomial<&'a mut [T]>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let polynomial_size = self.polynomial_size;
+        self.chunks
+            .next()
+            .map(|a| Polynomial::new(a, polynomial_size))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.chunks.size_hint()
+    }
+}
+
+impl<T> DoubleEndedIterator for PolynomialsMut<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let polynomial_size = self.polynomial_size;
+        self.chunks
+            .next_back()
+            .map(|a| Polynomial::new(a, polynomial_size))
+    }
+}
+
+impl<T> ExactSizeIterator for PolynomialsMut<'_, T> {}
+
+impl<'a, C: Container> IntoIterator for &'a PolynomialList<C> {
+    type Item = Polynomial<&'a [C::Item]>;
+    type IntoIter = Polynomials<'a, C::Item>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        Polynomials {
+            chunks: self.data.as_ref().chunks_exact(self.polynomial_size),
             polynomial_size: self.polynomial_size,
         }
     }
+}
 
-    pub fn as_view(&self) -> PolynomialList<&[u64]> {
-        PolynomialList {
-            data: self.data,
-            count: self.count,
+impl<'a, C: ContainerMut> IntoIterator for &'a mut PolynomialList<C> {
+    type Item = Polynomial<&'a mut [C::Item]>;
+    type IntoIter = PolynomialsMut<'a, C::Item>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        PolynomialsMut {
+            chunks: self.data.as_mut().chunks_exact_mut(self.polynomial_size),
             polynomial_size: self.polynomial_size,
         }
     }
 }
+
+fn checked_quotient(len: usize, divisor: usize) -> Result<usize, PolynomialListError> {
+    if divisor == 0 || !len.is_multiple_of(divisor) {
+        return Err(PolynomialListError::NotDivisible { len, divisor });
+    }
+    Ok(len / divisor)
+}
+
+impl<'a, T> PolynomialList<&'a [T]> {
+    /// Creates a list of polynomials of size `polynomial_size` spanning all of `data`, e.g. a
+    /// buffer received through FFI, inferring the count.
+    pub fn try_from_flat_slice(
+        data: &'a [T],
+        polynomial_size: usize,
+    ) -> Result<Self, PolynomialListError> {
+        let count = checked_quotient(data.len(), polynomial_size)?;
+        Ok(Self::new(
+            data,
+            PolynomialSize(polynomial_size),
+            PolynomialCount(count),
+        ))
+    }
+
+    /// Same as [`PolynomialList::try_from_flat_slice`], given the count instead of the polynomial
+    /// size.
+    pub fn try_from_flat_slice_with_count(
+        data: &'a [T],
+        count: usize,
+    ) -> Result<Self, PolynomialListError> {
+        let polynomial_size = checked_quotient(data.len(), count)?;
+        Ok(Self::new(
+            data,
+            PolynomialSize(polynomial_size),
+            PolynomialCount(count),
+        ))
+    }
+
+    /// Returns a value whose `Display` implementation prints the coefficients grouped per
+    /// polynomial, e.g. `[[a0, a1, a2], [b0, b1, b2]]`.
+    ///
+    /// Only the first and last three coefficients of large polynomials are printed, as in
+    /// `[[a0, a1, a2, .., a1021, a1022, a1023]]`. This is meant for debugging, the format is not
+    /// stable.
+    pub fn debug_polynomials(&self) -> DebugPolynomials<'_, T> {
+        DebugPolynomials {
+            list: PolynomialList {
+                data: self.data,
+                count: self.count,
+                polynomial_size: self.polynomial_size,
+            },
+        }
+    }
+
+    /// Returns an iterator over the polynomials of index `offset`, `offset + step`,
+    /// `offset + 2 * step`, ..., e.g. over the polynomials of one level when the levels are
+    /// interleaved.
+    ///
+    /// # Panics
+    ///
+    /// Panics unless `offset < step <= count`.
+    pub fn strided_iter(
+        &self,
+        step: usize,
+        offset: usize,
+    ) -> impl DoubleEndedIterator<Item = Polynomial<&[T]>> {
+        assert!(
+            offset < step && step <= self.count,
+            "invalid stride: offset is {offset}, step is {step} and count is {}",
+            self.count,
+        );
+        let polynomial_size = self.polynomial_size;
+        let data = self.data;
+        (offset..self.count).step_by(step).map(move |index| {
+            Polynomial::new(
+                &data[index * polynomial_size..(index + 1) * polynomial_size],
+                polynomial_size,
+            )
+        })
+    }
+
+    // Creates an iterator over borrowed sub-lists.
+    pub fn sublist_iter(
+        &self,
+        count: usize,
+    ) -> impl DoubleEndedIterator<Item = PolynomialList<&[T]>> {
+        let polynomial_size = self.polynomial_size;
+
+        debug_assert_eq!(self.count % count, 0);
+
+        self.data
+            .chunks_exact(count * polynomial_size)
+            .map(move |sub| PolynomialList {
+                data: sub,
+                polynomial_size,
+                count,
+            })
+    }
+
+    /// Splits the list into the first `mid` polynomials and the remaining `count - mid` ones.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `mid > count`.
+    pub fn split_at(&self, mid: usize) -> (PolynomialList<&[T]>, PolynomialList<&[T]>) {
+        assert!(
+            mid <= self.count,
+            "split index out of range: mid is {mid} but count is {}",
+            self.count,
+        );
+        let polynomial_size = self.polynomial_size;
+        let (left, right) = self.data.split_at(mid * polynomial_size);
+        (
+            PolynomialList::new(left, PolynomialSize(polynomial_size), PolynomialCount(mid)),
+            PolynomialList::new(
+                right,
+                PolynomialSize(polynomial_size),
+                PolynomialCount(self.count - mid),
+            ),
+        )
+    }
+
+    /// Returns the polynomials of `self` whose indices are in `range`, which is any kind of range,
+    /// e.g. `2..5`, `2..` or `..`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the start of the range is after its end, or if its end is after `count`.
+    pub fn polynomial_range(&self, range: impl RangeBounds<usize>) -> PolynomialList<&[T]> {
+        let start = match range.start_bound() {
+            Bound::Included(&start) => start,
+            Bound::Excluded(&start) => start + 1,
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&end) => end + 1,
+            Bound::Excluded(&end) => end,
+            Bound::Unbounded => self.count,
+        };
+        assert!(
+            start <= end && end <= self.count,
+            "polynomial range out of bounds: range is {start}..{end} but count is {}",
+            self.count,
+        );
+
+        let polynomial_size = self.polynomial_size;
+        PolynomialList::new(
+            &self.data[start * polynomial_size..end * polynomial_size],
+            PolynomialSize(polynomial_size),
+            PolynomialCount(end - start),
+        )
+    }
+
+    /// Writes the transpose of `self`, seen as a `count × polynomial_size` matrix of
+    /// coefficients, to `out`, i.e. the `i`-th coefficient of the `j`-th polynomial of `out` is
+    /// the `j`-th coefficient of the `i`-th polynomial of `self`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out.count != self.polynomial_size` or `out.polynomial_size != self.count`.
+    pub fn transpose_into(&self, out: &mut PolynomialList<&mut [T]>)
+    where
+        T: Copy,
+    {
+        assert_eq!(out.count, self.polynomial_size);
+        assert_eq!(out.polynomial_size, self.count);
+
+        for (i, polynomial) in self.iter_polynomial().enumerate() {
+            for (out_polynomial, &coefficient) in out.iter_polynomial_mut().zip(polynomial.iter()) {
+                out_polynomial.into_data()[i] = coefficient;
+            }
+        }
+    }
+}
+
+impl<T> PolynomialList<&mut [T]> {
+    /// Rotates the order of the polynomials of the list, so that the polynomial of index `k`
+    /// becomes the first one. The coefficients of each polynomial are left untouched. `k` is
+    /// taken modulo `count`.
+    pub fn rotate_polynomials_left(&mut self, k: usize) {
+        if self.count != 0 {
+            self.data.rotate_left(k % self.count * self.polynomial_size);
+        }
+    }
+
+    /// Rotates the order of the polynomials of the list, so that the first polynomial gets the
+    /// index `k`. The coefficients of each polynomial are left untouched. `k` is taken modulo
+    /// `count`.
+    pub fn rotate_polynomials_right(&mut self, k: usize) {
+        if self.count != 0 {
+            self.data
+                .rotate_right(k % self.count * self.polynomial_size);
+        }
+    }
+
+    /// Splits the list into the first `mid` polynomials and the remaining `count - mid` ones, as
+    /// two disjoint mutable views.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `mid > count`.
+    pub fn split_at_mut(
+        &mut self,
+        mid: usize,
+    ) -> (PolynomialList<&mut [T]>, PolynomialList<&mut [T]>) {
+        assert!(
+            mid <= self.count,
+            "split index out of range: mid is {mid} but count is {}",
+            self.count,
+        );
+        let polynomial_size = self.polynomial_size;
+        let count = self.count;
+        let (left, right) = self.data.split_at_mut(mid * polynomial_size);
+        (
+            PolynomialList::new(left, PolynomialSize(polynomial_size), PolynomialCount(mid)),
+            PolynomialList::new(
+                right,
+                PolynomialSize(polynomial_size),
+                PolynomialCount(count - mid),
+            ),
+        )
+    }
+
+    // Creates an iterator over mutably borrowed, disjoint sub-lists.
+    pub fn sublist_iter_mut(
+        &mut self,
+        count: usize,
+    ) -> impl DoubleEndedIterator<Item = PolynomialList<&mut [T]>> {
+        let polynomial_size = self.polynomial_size;
+
+        debug_assert_eq!(self.count % count, 0);
+
+        self.data
+            .chunks_exact_mut(count * polynomial_size)
+            .map(move |sub| PolynomialList {
+                data: sub,
+                polynomial_size,
+                count,
+            })
+    }
+}
+
+impl PolynomialList<&[u64]> {
+    /// Writes `f(a)` to `out` for every coefficient `a` of `self`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` does not have the same `count` and `polynomial_size` as `self`.
+    pub fn map_into(&self, out: &mut PolynomialList<&mut [u64]>, f: impl Fn(u64) -> u64) {
+        assert_eq!(out.count, self.count);
+        assert_eq!(out.polynomial_size, self.polynomial_size);
+
+        for (out, a) in zip_eq(out.data.iter_mut(), self.data) {
+            *out = f(*a);
+        }
+    }
+}
+
+impl PolynomialList<&mut [u64]> {
+    /// Sets every coefficient of every polynomial of the list to zero.
+    pub fn fill_with_zero(&mut self) {
+        self.data.fill(0);
+    }
+
+    /// Replaces every coefficient `a` of the list with `f(a)`.
+    pub fn map_in_place(&mut self, mut f: impl FnMut(u64) -> u64) {
+        for a in self.data.iter_mut() {
+            *a = f(*a);
+        }
+    }
+}
+
+#[cfg(feature = "parallel")]
+impl<T: Sync> PolynomialList<&[T]> {
+    pub fn par_iter_polynomial(&self) -> impl IndexedParallelIterator<Item = Polynomial<&'_ [T]>> {
+        let polynomial_size = self.polynomial_size;
+        self.data
+            .par_chunks_exact(polynomial_size)
+            .map(move |a| Polynomial::new(a, polynomial_size))
+    }
+}
+
+#[cfg(feature = "parallel")]
+impl<T: Send> PolynomialList<&mut [T]> {
+    pub fn par_iter_polynomial_mut(
+        &mut self,
+    ) -> impl IndexedParallelIterator<Item = Polynomial<&'_ mut [T]>> {
+        let polynomial_size = self.polynomial_size;
+        self.data
+            .par_chunks_exact_mut(polynomial_size)
+            .map(move |a| Polynomial::new(a, polynomial_size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Only uses `core` and `alloc`, and is also run under
+    /// `--no-default-features --features csprng`, where the library itself is `no_std`.
+    #[test]
+    fn alloc_only_owned_list() {
+        let polynomial_size = 4;
+        let mut list = PolynomialList::new(
+            alloc::vec![0_u64; 0],
+            PolynomialSize(polynomial_size),
+            PolynomialCount(0),
+        );
+        for i in 0..3_u64 {
+            let coefficients: [u64; 4] = core::array::from_fn(|j| i * 4 + j as u64);
+            list.push_polynomial(Polynomial::new(&coefficients[..], polynomial_size));
+        }
+        let data: Vec<u64> = list.into_data();
+        assert_eq!(data, (0..12).collect::<Vec<u64>>());
+    }
+
+    #[test]
+    fn enumerate_polynomial_indices() {
+        let polynomial_size = 4;
+        let mut list = PolynomialList::new(
+            vec![0_u64; polynomial_size * 3],
+            PolynomialSize(polynomial_size),
+            PolynomialCount(3),
+        );
+
+        for (i, polynomial) in list.enumerate_polynomial_mut() {
+            polynomial.into_data().fill(i as u64);
+        }
+        let indices: Vec<usize> = list.enumerate_polynomial().map(|(i, _)| i).collect();
+        assert_eq!(indices, [0, 1, 2]);
+        for (i, polynomial) in list.enumerate_polynomial() {
+            assert!(polynomial.iter().all(|a| *a == i as u64));
+        }
+        assert_eq!(list.enumerate_polynomial().next_back().unwrap().0, 2);
+    }
+
+    #[test]
+    fn iter_polynomial_is_scalar_generic() {
+        fn check<T: Copy + PartialEq + core::fmt::Debug + From<u8>>() {
+            let polynomial_size = 3;
+            let mut data: Vec<T> = (0..6_u8).map(T::from).collect();
+
+            let mut list = PolynomialList::new(
+                data.as_mut_slice(),
+                PolynomialSize(polynomial_size),
+                PolynomialCount(2),
+            );
+            for (i, polynomial) in list.iter_polynomial_mut().enumerate() {
+                polynomial.into_data()[0] = T::from(10 + i as u8);
+            }
+
+            let list = list.as_view();
+            let polynomials: Vec<Vec<T>> = list
+                .iter_polynomial()
+                .map(|p| p.iter().copied().collect())
+                .collect();
+            let expected = [[10, 1, 2], [11, 4, 5]].map(|p| p.map(T::from).to_vec());
+            assert_eq!(polynomials, expected);
+            assert_eq!(list.sublist_iter(1).count(), 2);
+        }
+
+        check::<u32>();
+        check::<u64>();
+    }
+
+    #[test]
+    fn owned_containers_iter_polynomial() {
+        fn first_coefficients<C: Container<Item = u64>>(list: &PolynomialList<C>) -> Vec<u64> {
+            list.iter_polynomial()
+                .map(|p| *p.iter().next().unwrap())
+                .collect()
+        }
+
+        let mut list = PolynomialList::new(vec![0_u64; 6], PolynomialSize(2), PolynomialCount(3));
+        for (i, polynomial) in list.iter_polynomial_mut().enumerate() {
+            polynomial.into_data()[0] = i as u64;
+        }
+        assert_eq!(first_coefficients(&list), [0, 1, 2]);
+        assert_eq!(first_coefficients(&list.as_view()), [0, 1, 2]);
+
+        let list = PolynomialList::new(
+            aligned_vec::avec![1_u64; 6],
+            PolynomialSize(3),
+            PolynomialCount(2),
+        );
+        assert_eq!(first_coefficients(&list), [1, 1]);
+        assert_eq!(list.as_view().iter_polynomial().count(), 2);
+    }
+
+    #[test]
+    fn boxed_slice_list() {
+        let polynomial_size = 4;
+        let data: Vec<u64> = (0..12).collect();
+        let ptr = data.as_ptr();
+
+        let mut list: PolynomialList<Box<[u64]>> =
+            PolynomialList::new(data, PolynomialSize(polynomial_size), PolynomialCount(3)).into();
+        assert_eq!(list.data.as_ptr(), ptr);
+        assert_eq!(list.count, 3);
+
+        for polynomial in list.iter_polynomial_mut() {
+            polynomial.into_data()[0] = 100;
+        }
+        let expected = [100, 1, 2, 3, 100, 5, 6, 7, 100, 9, 10, 11];
+        assert!(list.content_eq(&PolynomialList::new(
+            &expected[..],
+            PolynomialSize(polynomial_size),
+            PolynomialCount(3)
+        )));
+        assert_eq!(list.as_view().get_polynomial(2).into_data(), &expected[8..]);
+
+        let zeroed = PolynomialList::<Box<[u64]>>::new_zeroed(
+            PolynomialSize(polynomial_size),
+            PolynomialCount(2),
+        );
+        assert_eq!(zeroed.iter_polynomial().count(), 2);
+    }
+
+    #[test]
+    fn resized() {
+        let list = PolynomialList::new(
+            vec![1_u64, 2, 3, 4, 5, 6],
+            PolynomialSize(3),
+            PolynomialCount(2),
+        );
+
+        let grown = list.resized(5);
+        assert_eq!(grown.count, 2);
+        assert_eq!(grown.polynomial_size, 5);
+        assert_eq!(grown.data, [1, 2, 3, 0, 0, 4, 5, 6, 0, 0]);
+
+        let shrunk = list.resized(2);
+        assert_eq!(shrunk.count, 2);
+        assert_eq!(shrunk.polynomial_size, 2);
+        assert_eq!(shrunk.data, [1, 2, 4, 5]);
+
+        assert!(grown.resized(3).content_eq(&list));
+    }
+
+    #[test]
+    fn debug_polynomials() {
+        let data: Vec<u64> = (0..6).collect();
+        let list = PolynomialList::new(data.as_slice(), PolynomialSize(3), PolynomialCount(2));
+        assert_eq!(
+            list.debug_polynomials().to_string(),
+            "[[0, 1, 2], [3, 4, 5]]"
+        );
+
+        let data: Vec<u64> = (0..16).collect();
+        let list = PolynomialList::new(data.as_slice(), PolynomialSize(8), PolynomialCount(2));
+        assert_eq!(
+            list.debug_polynomials().to_string(),
+            "[[0, 1, 2, .., 5, 6, 7], [8, 9, 10, .., 13, 14, 15]]"
+        );
+
+        let list = PolynomialList::new(&data[..0], PolynomialSize(8), PolynomialCount(0));
+        assert_eq!(list.debug_polynomials().to_string(), "[]");
+    }
+
+    #[test]
+    fn bytes_round_trip() {
+        let data: Vec<u64> = (0..12).map(|i| u64::MAX - i).collect();
+        let list = PolynomialList::new(data.as_slice(), PolynomialSize(4), PolynomialCount(3));
+
+        let bytes = list.to_bytes();
+        assert_eq!(bytes.len(), 21 + 8 * 12);
+        assert_eq!(&bytes[..5], b"CCPL\x01");
+        assert!(PolynomialList::from_bytes(&bytes)
+            .unwrap()
+            .content_eq(&list));
+
+        let empty = PolynomialList::new(&data[..0], PolynomialSize(4), PolynomialCount(0));
+        assert!(PolynomialList::from_bytes(&empty.to_bytes())
+            .unwrap()
+            .content_eq(&empty));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn stream_polynomials_from_bytes() {
+        use std::io::{Cursor, ErrorKind};
+
+        let polynomial_size = 4;
+        let data: Vec<u64> = (0..12).map(|i| i * 0x0101_0101_0101_0101).collect();
+        let list = PolynomialList::new(
+            data.as_slice(),
+            PolynomialSize(polynomial_size),
+            PolynomialCount(3),
+        );
+        let bytes = list.to_bytes();
+        let coefficients = &bytes[BYTES_HEADER_LEN..];
+
+        let streamed: Vec<_> = stream_polynomials(Cursor::new(coefficients), polynomial_size)
+            .collect::<std::io::Result<_>>()
+            .unwrap();
+        assert_eq!(streamed.len(), 3);
+        for (streamed, expected) in streamed.iter().zip(list.iter_polynomial()) {
+            assert_eq!(streamed.as_view().into_data(), expected.into_data());
+        }
+
+        assert_eq!(
+            stream_polynomials(Cursor::new(&coefficients[..0]), polynomial_size).count(),
+            0
+        );
+
+        let truncated = &coefficients[..coefficients.len() - 3];
+        let mut stream = stream_polynomials(Cursor::new(truncated), polynomial_size);
+        assert_eq!(stream.next().unwrap().unwrap().into_data(), data[..4]);
+        assert_eq!(stream.next().unwrap().unwrap().into_data(), data[4..8]);
+        assert_eq!(
+            stream.next().unwrap().unwrap_err().kind(),
+            ErrorKind::UnexpectedEof
+        );
+        assert!(stream.next().is_none());
+    }
+
+    #[test]
+    fn bytes_corruption() {
+        let list = PolynomialList::new(
+            vec![1_u64, 2, 3, 4, 5, 6],
+            PolynomialSize(3),
+            PolynomialCount(2),
+        );
+        let bytes = list.to_bytes();
+        let from_bytes = PolynomialList::from_bytes;
+
+        let mut bad_magic = bytes.clone();
+        bad_magic[0] ^= 1;
+        assert_eq!(from_bytes(&bad_magic).unwrap_err(), DeserError::BadMagic);
+        assert_eq!(from_bytes(b"CX").unwrap_err(), DeserError::BadMagic);
+        assert_eq!(
+            from_bytes(&[]).unwrap_err(),
+            DeserError::Truncated {
+                expected: 21,
+                got: 0
+            }
+        );
+        assert_eq!(
+            from_bytes(b"CC").unwrap_err(),
+            DeserError::Truncated {
+                expected: 21,
+                got: 2
+            }
+        );
+
+        let mut bad_version = bytes.clone();
+        bad_version[4] = 2;
+        assert_eq!(
+            from_bytes(&bad_version).unwrap_err(),
+            DeserError::UnsupportedVersion(2)
+        );
+
+        assert_eq!(
+            from_bytes(&bytes[..10]).unwrap_err(),
+            DeserError::Truncated {
+                expected: 21,
+                got: 10
+            }
+        );
+        assert_eq!(
+            from_bytes(&bytes[..bytes.len() - 1]).unwrap_err(),
+            DeserError::Truncated {
+                expected: 69,
+                got: 68
+            }
+        );
+
+        let mut trailing = bytes.clone();
+        trailing.push(0);
+        assert_eq!(
+            from_bytes(&trailing).unwrap_err(),
+            DeserError::TrailingBytes {
+                expected: 69,
+                got: 70
+            }
+        );
+
+        let mut overflow = bytes;
+        overflow[5..13].copy_from_slice(&u64::MAX.to_le_bytes());
+        assert_eq!(
+            from_bytes(&overflow).unwrap_err(),
+            DeserError::SizeOverflow {
+                polynomial_size: u64::MAX,
+                count: 2
+            }
+        );
+    }
+
+    #[test]
+    fn map_coefficients() {
+        // encodes messages of `message_bits` bits in the most significant bits of the torus
+        let message_bits = 4;
+        let encode = |m: u64| m << (64 - message_bits);
+
+        let polynomial_size = 4;
+        let messages: Vec<u64> = (0..12).map(|i| i % 16).collect();
+        let list = PolynomialList::new(
+            messages.as_slice(),
+            PolynomialSize(polynomial_size),
+            PolynomialCount(3),
+        );
+
+        let mut expected = vec![0_u64; 12];
+        for (e, m) in expected.iter_mut().zip(&messages) {
+            *e = encode(*m);
+        }
+
+        let mut encoded = vec![0_u64; 12];
+        list.map_into(
+            &mut PolynomialList::new(
+                encoded.as_mut_slice(),
+                PolynomialSize(polynomial_size),
+                PolynomialCount(3),
+            ),
+            encode,
+        );
+        assert_eq!(encoded, expected);
+
+        let mut in_place = messages.clone();
+        let mut calls = 0;
+        PolynomialList::new(
+            in_place.as_mut_slice(),
+            PolynomialSize(polynomial_size),
+            PolynomialCount(3),
+        )
+        .map_in_place(|m| {
+            calls += 1;
+            encode(m)
+        });
+        assert_eq!(in_place, expected);
+        assert_eq!(calls, 12);
+    }
+
+    #[test]
+    #[should_panic]
+    fn map_into_checks_shape() {
+        let data = vec![0_u64; 12];
+        let mut out = vec![0_u64; 12];
+        PolynomialList::new(data.as_slice(), PolynomialSize(4), PolynomialCount(3)).map_into(
+            &mut PolynomialList::new(out.as_mut_slice(), PolynomialSize(3), PolynomialCount(4)),
+            |a| a,
+        );
+    }
+
+    #[test]
+    fn single_polynomial_round_trip() {
+        let data: Vec<u64> = (0..12).collect();
+        let list = PolynomialList::new(data.as_slice(), PolynomialSize(4), PolynomialCount(3));
+
+        let polynomial = list.as_single_polynomial();
+        assert_eq!(polynomial.polynomial_size, 12);
+        assert_eq!(polynomial.as_view().into_data(), data);
+
+        let relisted = polynomial.as_polynomial_list(6);
+        assert_eq!((relisted.count, relisted.polynomial_size), (2, 6));
+        assert!(polynomial.as_polynomial_list(4).content_eq(&list));
+    }
+
+    #[test]
+    #[should_panic(expected = "does not divide")]
+    fn as_polynomial_list_checks_divisibility() {
+        let data = [0_u64; 12];
+        Polynomial::new(&data[..], 12).as_polynomial_list(5);
+    }
+
+    #[test]
+    fn rotate_polynomials() {
+        let mut data: Vec<u64> = (0..12).collect();
+        let mut list =
+            PolynomialList::new(data.as_mut_slice(), PolynomialSize(3), PolynomialCount(4));
+
+        list.rotate_polynomials_left(1);
+        assert_eq!(list.data, [3, 4, 5, 6, 7, 8, 9, 10, 11, 0, 1, 2]);
+        list.rotate_polynomials_right(1);
+        assert_eq!(list.data, (0..12).collect::<Vec<_>>());
+
+        // taken modulo the count
+        list.rotate_polynomials_right(5);
+        assert_eq!(list.data, [9, 10, 11, 0, 1, 2, 3, 4, 5, 6, 7, 8]);
+        list.rotate_polynomials_left(4);
+        assert_eq!(list.data, [9, 10, 11, 0, 1, 2, 3, 4, 5, 6, 7, 8]);
+
+        PolynomialList::new(&mut [0_u64; 0][..], PolynomialSize(3), PolynomialCount(0))
+            .rotate_polynomials_left(1);
+    }
+
+    #[test]
+    fn strided_iter() {
+        let data: Vec<u64> = (0..12).collect();
+        let list = PolynomialList::new(data.as_slice(), PolynomialSize(2), PolynomialCount(6));
+
+        let even: Vec<_> = list
+            .strided_iter(2, 0)
+            .map(|p| p.into_data().to_vec())
+            .collect();
+        assert_eq!(even, [[0, 1], [4, 5], [8, 9]]);
+
+        let odd: Vec<_> = list.strided_iter(2, 1).map(|p| p.into_data()[0]).collect();
+        assert_eq!(odd, [2, 6, 10]);
+
+        assert_eq!(list.strided_iter(4, 3).count(), 1);
+        assert_eq!(list.strided_iter(6, 0).count(), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid stride")]
+    fn strided_iter_checks_offset() {
+        let data = [0_u64; 12];
+        let _ = PolynomialList::new(&data[..], PolynomialSize(2), PolynomialCount(6))
+            .strided_iter(2, 2);
+    }
+
+    #[cfg(feature = "ndarray")]
+    #[test]
+    fn array2_views() {
+        let data: Vec<u64> = (0..12).collect();
+        let mut list = PolynomialList::new(data, PolynomialSize(4), PolynomialCount(3));
+
+        let array = list.as_array2();
+        assert_eq!(array.dim(), (3, 4));
+        for i in 0..3 {
+            let polynomial = list.iter_polynomial().nth(i).unwrap();
+            for (j, &a) in polynomial.into_data().iter().enumerate() {
+                assert_eq!(array[[i, j]], a);
+            }
+        }
+
+        list.as_array2_mut()[[2, 1]] = 100;
+        assert_eq!(list.get_polynomial(2).into_data(), [8, 100, 10, 11]);
+
+        let copy = PolynomialList::from_array2(list.as_array2());
+        assert!(copy.content_eq(&list));
+
+        // a transposed view is not in C order, the rows are still the polynomials
+        let transposed = PolynomialList::from_array2(list.as_array2().t());
+        assert_eq!((transposed.count, transposed.polynomial_size), (4, 3));
+        assert_eq!(transposed.get_polynomial(1).into_data(), [1, 5, 100]);
+    }
+
+    #[test]
+    fn clone_from_view_grows() {
+        let mut list = PolynomialList::new(vec![7_u64; 4], PolynomialSize(2), PolynomialCount(2));
+        let src: Vec<u64> = (0..12).collect();
+
+        list.clone_from_view(PolynomialList::new(
+            src.as_slice(),
+            PolynomialSize(4),
+            PolynomialCount(3),
+        ));
+        assert_eq!((list.polynomial_size, list.count), (4, 3));
+        assert_eq!(list.data, src);
+    }
+
+    #[test]
+    fn clone_from_view_reuses_capacity() {
+        let mut list = PolynomialList::new(vec![7_u64; 12], PolynomialSize(4), PolynomialCount(3));
+        let (ptr, capacity) = (list.data.as_ptr(), list.data.capacity());
+        let src: Vec<u64> = (0..6).collect();
+
+        list.clone_from_view(PolynomialList::new(
+            src.as_slice(),
+            PolynomialSize(3),
+            PolynomialCount(2),
+        ));
+        assert_eq!((list.polynomial_size, list.count), (3, 2));
+        assert_eq!(list.data, src);
+        assert_eq!((list.data.as_ptr(), list.data.capacity()), (ptr, capacity));
+    }
+
+    #[test]
+    fn for_loop_over_borrowed_list() {
+        let data: Vec<u64> = (0..12).collect();
+        let mut list = PolynomialList::new(data, PolynomialSize(3), PolynomialCount(4));
+
+        for mut polynomial in &mut list {
+            polynomial.as_mut_view().into_data()[0] += 100;
+        }
+
+        let mut checksum = 0_u64;
+        for polynomial in &list {
+            assert_eq!(polynomial.polynomial_size, 3);
+            for &a in polynomial.into_data() {
+                checksum = checksum.wrapping_mul(31).wrapping_add(a);
+            }
+        }
+
+        let expected = list
+            .data
+            .iter()
+            .fold(0_u64, |acc, &a| acc.wrapping_mul(31).wrapping_add(a));
+        assert_eq!(checksum, expected);
+        assert_eq!(list.data[..4], [100, 1, 2, 103]);
+
+        let view = list.as_view();
+        assert_eq!((&view).into_iter().len(), 4);
+        assert_eq!(
+            (&view).into_iter().next_back().unwrap().into_data(),
+            [109, 10, 11]
+        );
+    }
+
+    #[test]
+    fn polynomial_range() {
+        let data: Vec<u64> = (0..12).collect();
+        let list = PolynomialList::new(data.as_slice(), PolynomialSize(2), PolynomialCount(6));
+
+        let range = list.polynomial_range(2..5);
+        assert_eq!(range.count, 3);
+        assert_eq!(range.polynomial_size, 2);
+        assert_eq!(range.data, [4, 5, 6, 7, 8, 9]);
+
+        assert_eq!(list.polynomial_range(4..).data, [8, 9, 10, 11]);
+        assert_eq!(list.polynomial_range(..1).data, [0, 1]);
+        assert_eq!(list.polynomial_range(1..=1).data, [2, 3]);
+        assert_eq!(list.polynomial_range(..).count, 6);
+        assert_eq!(list.polynomial_range(6..).count, 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "range is 3..7 but count is 6")]
+    fn polynomial_range_out_of_bounds() {
+        let data = vec![0_u64; 12];
+        PolynomialList::new(data.as_slice(), PolynomialSize(2), PolynomialCount(6))
+            .polynomial_range(3..7);
+    }
+
+    #[test]
+    #[should_panic(expected = "range is 4..2 but count is 6")]
+    fn polynomial_range_inverted() {
+        let data = vec![0_u64; 12];
+        #[allow(clippy::reversed_empty_ranges)]
+        PolynomialList::new(data.as_slice(), PolynomialSize(2), PolynomialCount(6))
+            .polynomial_range(4..2);
+    }
+
+    #[test]
+    fn new_unchecked_matches_new() {
+        let data: Vec<u64> = (0..12).collect();
+        let checked = PolynomialList::new(data.as_slice(), PolynomialSize(4), PolynomialCount(3));
+        let unchecked = unsafe {
+            PolynomialList::new_unchecked(data.as_slice(), PolynomialSize(4), PolynomialCount(3))
+        };
+        assert!(checked.content_eq(&unchecked));
+        assert_eq!(checked.data.as_ptr(), unchecked.data.as_ptr());
+    }
+
+    #[test]
+    fn dimensions_are_size_then_count() {
+        // the swapped call is a `compile_fail` doctest on `internals::PolynomialList`
+        let list = PolynomialList::new(vec![0_u64; 12], PolynomialSize(4), PolynomialCount(3));
+        assert_eq!((list.polynomial_size, list.count), (4, 3));
+    }
+
+    #[test]
+    fn try_from_flat_slice() {
+        let data: Vec<u64> = (0..12).collect();
+
+        let list = PolynomialList::try_from_flat_slice(&data, 4).unwrap();
+        assert_eq!((list.polynomial_size, list.count), (4, 3));
+        let list = PolynomialList::try_from_flat_slice_with_count(&data, 2).unwrap();
+        assert_eq!((list.polynomial_size, list.count), (6, 2));
+
+        assert_eq!(
+            PolynomialList::try_from_flat_slice(&data, 5).unwrap_err(),
+            PolynomialListError::NotDivisible {
+                len: 12,
+                divisor: 5
+            },
+        );
+        assert_eq!(
+            PolynomialList::try_from_flat_slice_with_count(&data, 0).unwrap_err(),
+            PolynomialListError::NotDivisible {
+                len: 12,
+                divisor: 0
+            },
+        );
+    }
+
+    #[test]
+    fn verify_invariants() {
+        let data: Vec<u64> = (0..12).collect();
+        let list = PolynomialList::try_from_flat_slice(&data, 4).unwrap();
+        assert_eq!(list.verify_invariants(), Ok(()));
+
+        let mut corrupted = list.clone();
+        corrupted.count = 4;
+        assert_eq!(
+            corrupted.verify_invariants(),
+            Err(PolynomialListError::LengthMismatch {
+                expected: 16,
+                got: 12
+            }),
+        );
+
+        // the product wraps around to the length of the data
+        let mut empty = PolynomialList::try_from_flat_slice(&data[..0], 4).unwrap();
+        empty.polynomial_size = usize::MAX / 2 + 1;
+        empty.count = 2;
+        assert_eq!(
+            empty.verify_invariants(),
+            Err(PolynomialListError::SizeOverflow {
+                polynomial_size: usize::MAX / 2 + 1,
+                count: 2
+            }),
+        );
+    }
+
+    #[test]
+    fn extend_from_list() {
+        let data: Vec<u64> = (0..20).collect();
+        let first = PolynomialList::new(&data[..8], PolynomialSize(4), PolynomialCount(2));
+        let second = PolynomialList::new(&data[8..], PolynomialSize(4), PolynomialCount(3));
+
+        let mut list = PolynomialList::new(Vec::new(), PolynomialSize(1), PolynomialCount(0));
+        list.extend_from_list(first.as_view());
+        assert!(list.content_eq(&first));
+
+        list.extend_from_list(second);
+        assert_eq!(list.count, 5);
+        for (polynomial, expected) in zip_eq(list.iter_polynomial(), data.chunks_exact(4)) {
+            assert_eq!(polynomial.into_data(), expected);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn extend_from_list_size_mismatch() {
+        let mut list = PolynomialList::new(vec![0_u64; 8], PolynomialSize(4), PolynomialCount(2));
+        list.extend_from_list(PolynomialList::new(
+            &[0_u64; 6][..],
+            PolynomialSize(3),
+            PolynomialCount(2),
+        ));
+    }
+
+    #[test]
+    fn get_polynomial() {
+        let mut list = PolynomialList::new(
+            (0..12_u64).collect::<Vec<_>>(),
+            PolynomialSize(4),
+            PolynomialCount(3),
+        );
+
+        assert_eq!(list.get_polynomial(0).into_data(), [0, 1, 2, 3]);
+        assert_eq!(list.get_polynomial(2).into_data(), [8, 9, 10, 11]);
+        assert!(list.try_get_polynomial(2).is_some());
+        assert!(list.try_get_polynomial(3).is_none());
+
+        list.get_polynomial_mut(2).into_data()[3] = 42;
+        assert_eq!(list.data[11], 42);
+    }
+
+    #[test]
+    fn size_overflow() {
+        // overflows a 64-bit `usize`, and thus a 32-bit one as well
+        let polynomial_size = usize::MAX / 2 + 1;
+        let count = 2;
+
+        assert_eq!(
+            PolynomialList::try_new(
+                &[0_u64][..],
+                PolynomialSize(polynomial_size),
+                PolynomialCount(count)
+            )
+            .unwrap_err(),
+            PolynomialListError::SizeOverflow {
+                polynomial_size,
+                count
+            },
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "size overflow")]
+    fn new_panics_on_size_overflow() {
+        PolynomialList::new(
+            &[0_u64][..],
+            PolynomialSize(usize::MAX / 2 + 1),
+            PolynomialCount(2),
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "index is 3 but count is 3")]
+    fn get_polynomial_out_of_range() {
+        let list = PolynomialList::new(vec![0_u64; 12], PolynomialSize(4), PolynomialCount(3));
+        list.get_polynomial(3);
+    }
+
+    #[test]
+    fn push_polynomial_and_concat() {
+        let polynomial_size = 3;
+        let data: Vec<u64> = (0..30).collect();
+
+        let mut pushed =
+            PolynomialList::new(vec![], PolynomialSize(polynomial_size), PolynomialCount(0));
+        for polynomial in data.chunks_exact(polynomial_size) {
+            pushed.push_polynomial(Polynomial::new(polynomial, polynomial_size));
+        }
+        assert_eq!(pushed.count, 10);
+        assert_eq!(pushed.data, data);
+
+        let concatenated = concat(&[
+            PolynomialList::new(
+                &data[..6],
+                PolynomialSize(polynomial_size),
+                PolynomialCount(2),
+            ),
+            PolynomialList::new(
+                &data[6..],
+                PolynomialSize(polynomial_size),
+                PolynomialCount(8),
+            ),
+        ]);
+        assert_eq!(concatenated.count, 10);
+        assert_eq!(concatenated.polynomial_size, polynomial_size);
+        assert_eq!(concatenated.data, data);
+    }
+
+    #[test]
+    fn into_sublists_round_trip() {
+        fn assert_send<T: Send>(_: &T) {}
+
+        let polynomial_size = 4;
+        let data: Vec<u64> = (0..(polynomial_size * 6) as u64).collect();
+        let list = PolynomialList::new(
+            data.clone(),
+            PolynomialSize(polynomial_size),
+            PolynomialCount(6),
+        );
+
+        let sublists: Vec<_> = list.into_sublists(2).collect();
+        assert_eq!(sublists.len(), 3);
+        assert_send(&sublists[0]);
+        for sublist in &sublists {
+            assert_eq!(sublist.count, 2);
+            assert_eq!(sublist.polynomial_size, polynomial_size);
+        }
+
+        let views: Vec<_> = sublists.iter().map(PolynomialList::as_view).collect();
+        assert_eq!(concat(&views).data, data);
+    }
+
+    #[test]
+    #[should_panic]
+    fn into_sublists_checks_divisibility() {
+        let list = PolynomialList::new(vec![0_u64; 12], PolynomialSize(4), PolynomialCount(3));
+        let _ = list.into_sublists(2);
+    }
+
+    #[test]
+    fn transpose_into_non_square() {
+        let data: Vec<u64> = (0..15).collect();
+        let list = PolynomialList::new(data.as_slice(), PolynomialSize(5), PolynomialCount(3));
+
+        let mut transposed = vec![0_u64; 15];
+        let mut out = PolynomialList::new(
+            transposed.as_mut_slice(),
+            PolynomialSize(3),
+            PolynomialCount(5),
+        );
+        list.transpose_into(&mut out);
+
+        for i in 0..3 {
+            for j in 0..5 {
+                assert_eq!(
+                    out.get_polynomial(j).into_data()[i],
+                    list.get_polynomial(i).into_data()[j]
+                );
+            }
+        }
+        assert_eq!(&out.data[..6], &[0, 5, 10, 1, 6, 11]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn transpose_into_checks_shape() {
+        let data = vec![0_u64; 15];
+        let mut out = vec![0_u64; 15];
+        PolynomialList::new(data.as_slice(), PolynomialSize(5), PolynomialCount(3)).transpose_into(
+            &mut PolynomialList::new(out.as_mut_slice(), PolynomialSize(5), PolynomialCount(3)),
+        );
+    }
+
+    #[cfg(feature = "test-tools")]
+    #[test]
+    fn new_random_is_reproducible() {
+        use rand::{rngs::StdRng, SeedableRng};
+
+        let a = PolynomialList::new_random(
+            PolynomialSize(8),
+            PolynomialCount(3),
+            &mut StdRng::seed_from_u64(0),
+        );
+        let b = PolynomialList::new_random(
+            PolynomialSize(8),
+            PolynomialCount(3),
+            &mut StdRng::seed_from_u64(0),
+        );
+        let c = PolynomialList::new_random(
+            PolynomialSize(8),
+            PolynomialCount(3),
+            &mut StdRng::seed_from_u64(1),
+        );
+
+        assert_eq!((a.polynomial_size, a.count), (8, 3));
+        assert_eq!(a.data, b.data);
+        assert_ne!(a.data, c.data);
+    }
+
+    #[test]
+    fn new_zeroed_is_container_generic() {
+        fn check<C: ContainerOwned<Item = u64>>() {
+            let list = PolynomialList::<C>::new_zeroed(PolynomialSize(4), PolynomialCount(3));
+            assert_eq!((list.polynomial_size, list.count), (4, 3));
+            assert_eq!(list.data.as_ref(), &[0; 12]);
+        }
+
+        check::<Vec<u64>>();
+        check::<aligned_vec::AVec<u64>>();
+        check::<aligned_vec::ABox<[u64]>>();
+    }
+
+    #[test]
+    fn aligned_vec_backs_list() {
+        use crate::implementation::AlignedVec;
+
+        fn check<const ALIGN: usize>() {
+            let data = AlignedVec::<ALIGN>::from_iter(ALIGN, 0..24);
+            assert_eq!(data.as_ptr() as usize % ALIGN, 0);
+            assert_eq!(data.len(), 24);
+
+            let list = PolynomialList::new(data, PolynomialSize(8), PolynomialCount(3));
+            assert_eq!(list.get_polynomial(1).into_data()[0], 8);
+
+            let zeroed = PolynomialList::<AlignedVec<ALIGN>>::new_zeroed(
+                PolynomialSize(8),
+                PolynomialCount(3),
+            );
+            assert_eq!(zeroed.data.as_ptr() as usize % ALIGN, 0);
+            assert_eq!(zeroed.data.as_slice(), &[0; 24]);
+        }
+
+        check::<32>();
+        check::<64>();
+        check::<4096>();
+    }
+
+    #[test]
+    fn from_polynomials_round_trips() {
+        let polynomial_size = 4;
+        let data: Vec<u64> = (0..20).collect();
+        let list = PolynomialList::new(
+            data.as_slice(),
+            PolynomialSize(polynomial_size),
+            PolynomialCount(5),
+        );
+
+        let collected = PolynomialList::from_polynomials(list.iter_polynomial(), polynomial_size);
+        assert_eq!(
+            (collected.polynomial_size, collected.count),
+            (polynomial_size, 5)
+        );
+        for (a, b) in collected.iter_polynomial().zip(list.iter_polynomial()) {
+            assert_eq!(a.into_data(), b.into_data());
+        }
+
+        let empty = PolynomialList::<Vec<u64>>::from_polynomials(core::iter::empty(), 4);
+        assert_eq!((empty.polynomial_size, empty.count), (4, 0));
+        assert!(empty.data.is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn from_polynomials_checks_size() {
+        let data = [0_u64; 3];
+        let _ =
+            PolynomialList::from_polynomials(core::iter::once(Polynomial::new(&data[..], 3)), 4);
+    }
+
+    #[test]
+    fn content_eq_across_containers() {
+        let owned = PolynomialList::new(
+            (0..12_u64).collect::<Vec<_>>(),
+            PolynomialSize(4),
+            PolynomialCount(3),
+        );
+        let data: Vec<u64> = (0..12).collect();
+
+        assert!(owned.content_eq(&PolynomialList::new(
+            data.as_slice(),
+            PolynomialSize(4),
+            PolynomialCount(3)
+        )));
+        assert!(
+            PolynomialList::new(data.as_slice(), PolynomialSize(4), PolynomialCount(3))
+                .content_eq(&owned)
+        );
+        // same coefficients, different shape
+        assert!(!owned.content_eq(&PolynomialList::new(
+            data.as_slice(),
+            PolynomialSize(6),
+            PolynomialCount(2)
+        )));
+
+        let mut other = data.clone();
+        other[7] = 0;
+        assert!(!owned.content_eq(&PolynomialList::new(
+            other.as_slice(),
+            PolynomialSize(4),
+            PolynomialCount(3)
+        )));
+    }
+
+    #[test]
+    fn try_new_checks_length() {
+        let data = vec![0_u64; 12];
+
+        let list = PolynomialList::try_new(data.as_slice(), PolynomialSize(4), PolynomialCount(3))
+            .unwrap();
+        assert_eq!(list.count, 3);
+        assert_eq!(list.polynomial_size, 4);
+
+        assert_eq!(
+            PolynomialList::try_new(data.as_slice(), PolynomialSize(4), PolynomialCount(4))
+                .unwrap_err(),
+            PolynomialListError::LengthMismatch {
+                expected: 16,
+                got: 12
+            },
+        );
+    }
+
+    #[test]
+    fn sublist_iter_mut_is_disjoint() {
+        let polynomial_size = 4;
+        let mut data = vec![0_u64; polynomial_size * 6];
+        let mut list = PolynomialList::new(
+            data.as_mut_slice(),
+            PolynomialSize(polynomial_size),
+            PolynomialCount(6),
+        );
+
+        // all the sub-lists are alive at the same time, and written in an interleaved order
+        {
+            let mut sublists: Vec<_> = list.sublist_iter_mut(2).collect();
+            assert_eq!(sublists.len(), 3);
+            for round in 0..2 {
+                for (i, sublist) in sublists.iter_mut().enumerate() {
+                    assert_eq!(sublist.count, 2);
+                    assert_eq!(sublist.polynomial_size, polynomial_size);
+                    sublist.data[round * polynomial_size..(round + 1) * polynomial_size]
+                        .fill((10 * i + round) as u64);
+                }
+            }
+        }
+
+        for (j, polynomial) in list.as_view().iter_polynomial().enumerate() {
+            let expected = (10 * (j / 2) + j % 2) as u64;
+            assert!(polynomial.iter().all(|a| *a == expected));
+        }
+    }
+
+    #[test]
+    fn split_at_reconstructs_list() {
+        let polynomial_size = 3;
+        let data: Vec<u64> = (0..15).collect();
+        let list = PolynomialList::new(
+            data.as_slice(),
+            PolynomialSize(polynomial_size),
+            PolynomialCount(5),
+        );
+
+        for mid in 0..=5 {
+            let (left, right) = list.split_at(mid);
+            assert_eq!((left.count, right.count), (mid, 5 - mid));
+            assert_eq!(left.polynomial_size, polynomial_size);
+            assert_eq!(right.polynomial_size, polynomial_size);
+
+            let joined = concat(&[left, right]);
+            assert_eq!(joined.count, 5);
+            assert_eq!(joined.data, data);
+        }
+    }
+
+    #[test]
+    fn split_at_mut_is_disjoint() {
+        let polynomial_size = 3;
+        let mut data = vec![0_u64; 15];
+        {
+            let mut list = PolynomialList::new(
+                data.as_mut_slice(),
+                PolynomialSize(polynomial_size),
+                PolynomialCount(5),
+            );
+
+            // both halves are written to while alive at the same time
+            let (mut left, mut right) = list.split_at_mut(2);
+            for (a, b) in left.iter_polynomial_mut().zip(right.iter_polynomial_mut()) {
+                a.into_data()[0] = 1;
+                b.into_data()[0] = 2;
+            }
+            assert_eq!((left.count, right.count), (2, 3));
+        }
+
+        assert_eq!(data, [1, 0, 0, 1, 0, 0, 2, 0, 0, 2, 0, 0, 0, 0, 0]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn split_at_out_of_range() {
+        let data = vec![0_u64; 6];
+        let _ =
+            PolynomialList::new(data.as_slice(), PolynomialSize(3), PolynomialCount(2)).split_at(3);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trip() {
+        for (polynomial_size, count) in [(1, 1), (4, 3), (1024, 2)] {
+            let data: Vec<u64> = (0..(polynomial_size * count) as u64)
+                .map(|i| i.wrapping_mul(0x9e37_79b9_7f4a_7c15))
+                .collect();
+            let list = PolynomialList::new(
+                data,
+                PolynomialSize(polynomial_size),
+                PolynomialCount(count),
+            );
+
+            let bytes = bincode::serialize(&list).unwrap();
+            let decoded: PolynomialList<Vec<u64>> = bincode::deserialize(&bytes).unwrap();
+            assert_eq!(decoded.data, list.data);
+            assert_eq!(decoded.count, count);
+            assert_eq!(decoded.polynomial_size, polynomial_size);
+
+            let json = serde_json::to_string(&list).unwrap();
+            let decoded: PolynomialList<Vec<u64>> = serde_json::from_str(&json).unwrap();
+            assert_eq!(decoded.data, list.data);
+            assert_eq!(decoded.count, count);
+            assert_eq!(decoded.polynomial_size, polynomial_size);
+        }
+
+        let inconsistent = r#"{"data":[1,2,3],"count":2,"polynomial_size":2}"#;
+        assert!(serde_json::from_str::<PolynomialList<Vec<u64>>>(inconsistent).is_err());
+
+        let polynomial = Polynomial::new(vec![1_u64, 2, 3], 3);
+        let json = serde_json::to_string(&polynomial).unwrap();
+        let decoded: Polynomial<Vec<u64>> = serde_json::from_str(&json).unwrap();
+        assert_eq!(decoded.into_data(), vec![1, 2, 3]);
+
+        let inconsistent = r#"{"data":[1,2,3],"polynomial_size":2}"#;
+        assert!(serde_json::from_str::<Polynomial<Vec<u64>>>(inconsistent).is_err());
+    }
+
+    #[cfg(feature = "zeroize")]
+    #[test]
+    fn zeroizing_list_is_wiped_on_drop() {
+        use alloc::rc::Rc;
+        use core::cell::Cell;
+        use zeroize::{Zeroize, Zeroizing};
+
+        struct RecordingContainer {
+            data: Vec<u64>,
+            zeroed: Rc<Cell<bool>>,
+        }
+        impl AsRef<[u64]> for RecordingContainer {
+            fn as_ref(&self) -> &[u64] {
+                &self.data
+            }
+        }
+        impl Container for RecordingContainer {
+            type Item = u64;
+
+            unsafe fn zeroize_owned(&mut self) {
+                self.zeroize();
+            }
+        }
+        impl Zeroize for RecordingContainer {
+            fn zeroize(&mut self) {
+                self.data.zeroize();
+                self.zeroed.set(self.data.iter().all(|a| *a == 0));
+            }
+        }
+
+        let zeroed = Rc::new(Cell::new(false));
+        let list = PolynomialList::new(
+            Zeroizing::new(RecordingContainer {
+                data: vec![1; 8],
+                zeroed: zeroed.clone(),
+            }),
+            PolynomialSize(4),
+            PolynomialCount(2),
+        );
+        assert_eq!(list.iter_polynomial().count(), 2);
+        assert!(!zeroed.get());
+        drop(list);
+        assert!(zeroed.get());
+
+        // an owned container is wiped even without `Zeroizing`
+        let zeroed = Rc::new(Cell::new(false));
+        let list = PolynomialList::new(
+            RecordingContainer {
+                data: vec![1; 8],
+                zeroed: zeroed.clone(),
+            },
+            PolynomialSize(4),
+            PolynomialCount(2),
+        );
+        assert!(!zeroed.get());
+        drop(list);
+        assert!(zeroed.get());
+
+        // dropping a view leaves the data alone
+        let mut data = vec![1_u64; 8];
+        drop(PolynomialList::new(
+            data.as_mut_slice(),
+            PolynomialSize(4),
+            PolynomialCount(2),
+        ));
+        assert_eq!(data, [1; 8]);
+
+        let mut vec = vec![1_u64; 8];
+        // SAFETY: all-zero bytes are a valid `u64`.
+        unsafe { vec.zeroize_owned() };
+        assert_eq!(vec, [0; 8]);
+
+        let mut list = PolynomialList::new(vec![1_u64; 8], PolynomialSize(4), PolynomialCount(2));
+        list.zeroize();
+        assert!(list.data.iter().all(|a| *a == 0));
+
+        fn assert_zeroize_on_drop<T: zeroize::ZeroizeOnDrop>() {}
+        assert_zeroize_on_drop::<PolynomialList<Vec<u64>>>();
+        assert_zeroize_on_drop::<PolynomialList<Box<[u64]>>>();
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn par_iter_polynomial_matches_serial() {
+        let polynomial_size = 16;
+        let count = 64;
+        let mut data: Vec<u64> = (0..(polynomial_size * count) as u64)
+            .map(|i| i.wrapping_mul(0x9e37_79b9_7f4a_7c15))
+            .collect();
+
+        let polynomial_sum =
+            |p: Polynomial<&[u64]>| p.iter().fold(0_u64, |acc, a| acc.wrapping_add(*a));
+
+        {
+            let list = PolynomialList::new(
+                data.as_slice(),
+                PolynomialSize(polynomial_size),
+                PolynomialCount(count),
+            );
+            let serial: Vec<u64> = list.iter_polynomial().map(polynomial_sum).collect();
+            let parallel: Vec<u64> = list.par_iter_polynomial().map(polynomial_sum).collect();
+            assert_eq!(serial, parallel);
+        }
+
+        let mut list = PolynomialList::new(
+            data.as_mut_slice(),
+            PolynomialSize(polynomial_size),
+            PolynomialCount(count),
+        );
+        list.par_iter_polynomial_mut()
+            .enumerate()
+            .for_each(|(i, p)| {
+                p.into_data().fill(i as u64);
+            });
+        for (i, p) in list.as_view().iter_polynomial().enumerate() {
+            assert!(p.iter().all(|a| *a == i as u64));
+        }
+    }
+}
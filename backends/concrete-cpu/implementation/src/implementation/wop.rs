@@ -1,6 +1,6 @@
 #![allow(clippy::too_many_arguments)]
 
-use std::cmp::Ordering;
+use core::cmp::Ordering;
 
 use aligned_vec::CACHELINE_ALIGN;
 use dyn_stack::{DynStack, ReborrowMut, SizeOverflow, StackReq};
@@ -19,6 +19,7 @@ use super::types::polynomial::Polynomial;
 use super::types::polynomial_list::PolynomialList;
 use super::types::{
     BootstrapKey, DecompParams, GgswCiphertext, GlweParams, LweCiphertext, LweKeyswitchKey,
+    PolynomialCount, PolynomialSize,
 };
 use super::{Container, Split};
 
@@ -494,7 +495,7 @@ impl<C: Container<Item = f64>> FourierGgswCiphertextList<C> {
         C: Split,
     {
         self.fourier
-            .data
+            .into_data()
             .split_into(self.count)
             .map(move |slice| GgswCiphertext::new(slice, self.glwe_params, self.decomp_params))
     }
@@ -506,7 +507,7 @@ impl<C: Container<Item = f64>> FourierGgswCiphertextList<C> {
         let glwe_dim = self.glwe_params.dimension;
         let polynomial_size = self.fourier.polynomial_size;
 
-        let (left, right) = self.fourier.data.split_at(
+        let (left, right) = self.fourier.into_data().split_at(
             mid * polynomial_size * (glwe_dim + 1) * (glwe_dim + 1) * self.decomp_params.level,
         );
         (
@@ -798,6 +799,7 @@ pub fn circuit_bootstrap_boolean_vertical_packing(
     }
 }
 
+#[cfg(feature = "std")]
 fn print_ct(ct: u64) {
     print!("{}", (((ct >> 53) + 1) >> 1) % (1 << 10));
 }
@@ -902,8 +904,8 @@ pub fn vertical_packing(
 
             let small_luts = PolynomialList::new(
                 lut.into_data(),
-                polynomial_size,
-                1 << (log_lut_number - log_poly_size),
+                PolynomialSize(polynomial_size),
+                PolynomialCount(1 << (log_lut_number - log_poly_size)),
             );
 
             cmux_tree_memory_optimized(
@@ -959,7 +961,11 @@ pub fn blind_rotate(
             .collect_aligned(CACHELINE_ALIGN, ct_0.as_view().into_data().iter().copied());
         let mut ct_1 = GlweCiphertext::new(&mut *ct1_data, ct_0.glwe_params);
 
-        for a in ct_1.as_mut_view().into_polynomial_list().iter_polynomial() {
+        for a in ct_1
+            .as_mut_view()
+            .into_polynomial_list()
+            .iter_polynomial_mut()
+        {
             update_with_wrapping_unit_monomial_div(a, monomial_degree);
         }
         monomial_degree <<= 1;
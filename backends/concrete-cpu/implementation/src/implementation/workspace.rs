@@ -0,0 +1,91 @@
+use alloc::vec::Vec;
+use dyn_stack::{DynStack, GlobalMemBuffer, StackReq};
+
+use super::types::polynomial::Polynomial;
+use super::types::polynomial_list::PolynomialList;
+use super::types::{PolynomialCount, PolynomialSize};
+
+/// Reusable scratch memory, to avoid allocating in every call of a routine run in a loop.
+///
+/// The buffers grow to the largest size requested so far and never shrink, so that once every
+/// size has been requested, no more allocations take place.
+///
+/// The values of the handed out polynomials are whatever was left by the previous user, they must
+/// be initialized before being read.
+pub struct Workspace {
+    polynomials: Vec<u64>,
+    mem: GlobalMemBuffer,
+    mem_req: StackReq,
+}
+
+impl Default for Workspace {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Workspace {
+    /// Creates an empty workspace, which does not allocate until memory is requested.
+    pub fn new() -> Self {
+        Self {
+            polynomials: Vec::new(),
+            mem: GlobalMemBuffer::new(StackReq::empty()),
+            mem_req: StackReq::empty(),
+        }
+    }
+
+    /// Returns a slice of `len` elements of the `u64` buffer.
+    pub fn buffer(&mut self, len: usize) -> &mut [u64] {
+        if self.polynomials.len() < len {
+            self.polynomials.resize(len, 0);
+        }
+        &mut self.polynomials[..len]
+    }
+
+    pub fn polynomial_mut(&mut self, polynomial_size: usize) -> Polynomial<&mut [u64]> {
+        Polynomial::new(self.buffer(polynomial_size), polynomial_size)
+    }
+
+    pub fn polynomial_list_mut(
+        &mut self,
+        polynomial_size: PolynomialSize,
+        count: PolynomialCount,
+    ) -> PolynomialList<&mut [u64]> {
+        let len = polynomial_size
+            .0
+            .checked_mul(count.0)
+            .expect("size overflow in workspace request");
+        PolynomialList::new(self.buffer(len), polynomial_size, count)
+    }
+
+    /// Returns a stack satisfying `req`, for the routines taking a [`DynStack`], such as
+    /// [`Polynomial::update_with_fft_mul`] or
+    /// [`external_product`](super::external_product::external_product).
+    ///
+    /// This memory is separate from the `u64` buffer.
+    pub fn stack(&mut self, req: StackReq) -> DynStack<'_> {
+        if req.size_bytes() > self.mem_req.size_bytes()
+            || req.align_bytes() > self.mem_req.align_bytes()
+        {
+            self.mem_req = self.mem_req.or(req);
+            self.mem = GlobalMemBuffer::new(self.mem_req);
+        }
+        DynStack::new(&mut self.mem)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn buffers_grow_but_do_not_shrink() {
+        let mut workspace = Workspace::new();
+        workspace
+            .polynomial_list_mut(PolynomialSize(16), PolynomialCount(4))
+            .fill_with_zero();
+        workspace.polynomial_mut(8).set_to_constant(1);
+        assert_eq!(workspace.polynomials.len(), 64);
+        assert_eq!(workspace.buffer(3), [1, 0, 0]);
+    }
+}
@@ -1,8 +1,53 @@
 #![allow(clippy::missing_safety_doc, dead_code)]
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
 #![cfg_attr(feature = "nightly", feature(stdsimd))]
 #![cfg_attr(feature = "nightly", feature(avx512_target_feature))]
 
+#[macro_use]
 extern crate alloc;
 
 pub mod c_api;
 mod implementation;
+
+/// Internals used by the fuzz targets in `fuzz/`, which cargo-fuzz builds with `--cfg fuzzing`.
+#[cfg(fuzzing)]
+#[doc(hidden)]
+pub mod fuzzing {
+    pub use crate::implementation::types::polynomial_list::{PolynomialList, PolynomialListError};
+    pub use crate::implementation::types::{PolynomialCount, PolynomialSize};
+}
+
+/// Internals used by the benchmarks in `benches/polynomial.rs` and by the integration tests that
+/// need more than the C API, enabled by the `internals` feature.
+#[cfg(feature = "internals")]
+#[doc(hidden)]
+pub mod internals {
+    pub use crate::implementation::decomposer::SignedDecomposer;
+    pub use crate::implementation::external_product::{
+        decompose_and_forward, decompose_and_forward_scratch, external_product_with_scratch,
+    };
+    pub use crate::implementation::fft::{Fft, Twisties};
+    pub use crate::implementation::ntt::Ntt;
+    #[cfg(feature = "parallel")]
+    pub use crate::implementation::polynomial::batch_forward_negacyclic_par;
+    pub use crate::implementation::polynomial::{
+        batch_forward_negacyclic, batch_forward_negacyclic_scratch, fft_mul_scratch,
+        wrapping_add_scalar, wrapping_sub_scalar,
+    };
+    pub use crate::implementation::types::fourier_polynomial::FourierPolynomial;
+    pub use crate::implementation::types::fourier_polynomial_list::FourierPolynomialList;
+    pub use crate::implementation::types::polynomial::{ArrayPolynomial, Polynomial};
+    /// The dimensions are distinct types, so they cannot be passed in the wrong order:
+    ///
+    /// ```compile_fail
+    /// use concrete_cpu::internals::{PolynomialCount, PolynomialList, PolynomialSize};
+    ///
+    /// let list = PolynomialList::new(vec![0_u64; 12], PolynomialCount(3), PolynomialSize(4));
+    /// ```
+    pub use crate::implementation::types::polynomial_list::PolynomialList;
+    pub use crate::implementation::types::{
+        BootstrapKey, DecompParams, GgswCiphertext, GlweCiphertext, GlweParams, LweCiphertext,
+        PolynomialCount, PolynomialSize,
+    };
+    pub use crate::implementation::workspace::Workspace;
+}
@@ -0,0 +1,137 @@
+//! Checks that the `*_with_scratch` routines stop allocating once their [`Workspace`] is warm.
+//!
+//! This is an integration test so that the counting allocator only replaces the allocator of
+//! this test binary.
+
+use concrete_cpu::internals::*;
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+/// Counts the allocations of each thread, so that tests running in parallel do not interfere.
+struct CountingAllocator;
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let _ = ALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+fn allocations() -> usize {
+    ALLOCATIONS.with(|count| count.get())
+}
+
+// Runs `run` once to warm `workspace` up, then checks that 100 more runs do not allocate.
+fn assert_no_allocation_after_warm_up(mut run: impl FnMut(&mut Workspace)) {
+    let mut workspace = Workspace::new();
+
+    let start = allocations();
+    run(&mut workspace);
+    assert!(allocations() > start);
+
+    let before = allocations();
+    for _ in 0..100 {
+        run(&mut workspace);
+    }
+    assert_eq!(allocations(), before);
+}
+
+#[test]
+fn polynomial_mul() {
+    let lhs: Vec<u64> = (0..256).map(|i| i % 7).collect();
+    let rhs: Vec<u64> = (0..256_u64)
+        .map(|i| i.wrapping_mul(0x9e37_79b9_7f4a_7c15))
+        .collect();
+    let mut out = vec![0_u64; 256];
+    let fft = Fft::new(256);
+    let fft = fft.as_view();
+
+    // the smaller size must not need any more memory after the larger one
+    assert_no_allocation_after_warm_up(|workspace| {
+        for polynomial_size in [256, 64] {
+            let lhs = Polynomial::new(&lhs[..polynomial_size], polynomial_size);
+            let rhs = Polynomial::new(&rhs[..polynomial_size], polynomial_size);
+            let mut out = Polynomial::new(&mut out[..polynomial_size], polynomial_size);
+
+            out.update_with_wrapping_karatsuba_mul_with_scratch(
+                lhs.as_view(),
+                rhs.as_view(),
+                workspace,
+            );
+            if polynomial_size == 256 {
+                out.update_with_fft_mul_with_scratch(lhs, rhs, fft, workspace);
+            }
+        }
+    });
+}
+
+#[test]
+fn ntt_mul() {
+    let lhs: Vec<u64> = (0..1024).map(|i| i % 12289).collect();
+    let rhs: Vec<u64> = (0..1024).map(|i| (i * i) % 12289).collect();
+    let mut out = vec![0_u64; 1024];
+    let ntt = Ntt::new(1024, 12289, 1945);
+
+    assert_no_allocation_after_warm_up(|workspace| {
+        ntt.negacyclic_mul_with_scratch(
+            Polynomial::new(out.as_mut_slice(), 1024),
+            Polynomial::new(lhs.as_slice(), 1024),
+            Polynomial::new(rhs.as_slice(), 1024),
+            workspace,
+        );
+    });
+}
+
+#[test]
+fn external_product_and_bootstrap() {
+    let glwe_params = GlweParams {
+        dimension: 1,
+        polynomial_size: 256,
+    };
+    let decomp_params = DecompParams {
+        level: 2,
+        base_log: 8,
+    };
+    let lwe_dimension = 4;
+    let fft = Fft::new(glwe_params.polynomial_size);
+    let fft = fft.as_view();
+
+    let ggsw = vec![0.0; GgswCiphertext::<&[f64]>::data_len(glwe_params, decomp_params.level)];
+    let bsk = vec![
+        0.0;
+        BootstrapKey::<&[f64]>::data_len(glwe_params, decomp_params.level, lwe_dimension)
+    ];
+    let glwe = vec![0_u64; GlweCiphertext::<&[u64]>::data_len(glwe_params)];
+    let mut out = glwe.clone();
+    let lwe_in = vec![0_u64; lwe_dimension + 1];
+    let mut lwe_out = vec![0_u64; glwe_params.lwe_dimension() + 1];
+
+    assert_no_allocation_after_warm_up(|workspace| {
+        external_product_with_scratch(
+            GlweCiphertext::new(out.as_mut_slice(), glwe_params),
+            GgswCiphertext::new(ggsw.as_slice(), glwe_params, decomp_params),
+            GlweCiphertext::new(glwe.as_slice(), glwe_params),
+            fft,
+            workspace,
+        );
+        BootstrapKey::new(bsk.as_slice(), glwe_params, lwe_dimension, decomp_params)
+            .bootstrap_with_scratch(
+                LweCiphertext::new(lwe_out.as_mut_slice(), glwe_params.lwe_dimension()),
+                LweCiphertext::new(lwe_in.as_slice(), lwe_dimension),
+                GlweCiphertext::new(glwe.as_slice(), glwe_params),
+                fft,
+                workspace,
+            );
+    });
+}